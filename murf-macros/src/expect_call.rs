@@ -8,11 +8,11 @@ use syn::{
     parse2,
     punctuated::Punctuated,
     token::{Comma, Gt, Lt, PathSep},
-    AngleBracketedGenericArguments, Expr, GenericArgument, Path, PathArguments,
+    AngleBracketedGenericArguments, Error, Expr, GenericArgument, Path, PathArguments,
     Result as ParseResult, Token, Type,
 };
 
-use crate::misc::{format_expect_call, ident_murf, IterEx};
+use crate::misc::{format_assert_call, format_expect_call, format_on_call, ident_murf, IterEx};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub(crate) enum CallMode {
@@ -20,7 +20,14 @@ pub(crate) enum CallMode {
     Static,
 }
 
-pub(crate) fn exec(input: TokenStream, mode: CallMode) -> TokenStream {
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum CallKind {
+    Expect,
+    OnCall,
+    Assert,
+}
+
+pub(crate) fn exec(input: TokenStream, mode: CallMode, kind: CallKind) -> TokenStream {
     let mut call: Call = match parse2(input) {
         Ok(mock) => mock,
         Err(err) => {
@@ -28,6 +35,12 @@ pub(crate) fn exec(input: TokenStream, mode: CallMode) -> TokenStream {
         }
     };
     call.mode = mode;
+    call.kind = kind;
+
+    if let (Some(times), false) = (&call.times, kind == CallKind::Assert) {
+        return Error::new_spanned(times, "`times` is only supported by `assert_called!`")
+            .to_compile_error();
+    }
 
     call.into_token_stream()
 }
@@ -39,6 +52,8 @@ struct Call {
     generics: Punctuated<GenericArgument, Token![,]>,
     args: Punctuated<Expr, Comma>,
     mode: CallMode,
+    kind: CallKind,
+    times: Option<Expr>,
 }
 
 impl Parse for Call {
@@ -66,6 +81,21 @@ impl Parse for Call {
         parenthesized!(content in input);
         let args = content.parse_terminated(Expr::parse, Token![,])?;
 
+        let times = if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+
+            let ident = input.parse::<Ident>()?;
+            if ident != "times" {
+                return Err(Error::new_spanned(ident, "Expected `times`"));
+            }
+
+            input.parse::<Token![=]>()?;
+
+            Some(input.parse::<Expr>()?)
+        } else {
+            None
+        };
+
         Ok(Self {
             obj,
             as_trait,
@@ -73,6 +103,8 @@ impl Parse for Call {
             generics,
             args,
             mode: CallMode::Static,
+            kind: CallKind::Expect,
+            times,
         })
     }
 }
@@ -86,13 +118,19 @@ impl ToTokens for Call {
             generics,
             args,
             mode,
+            kind,
+            times,
         } = self;
 
         let ident_murf = ident_murf();
 
         let desc = quote!(format!("at {}:{}", file!(), line!()));
         let obj = obj.to_token_stream();
-        let method = format_expect_call(method, as_trait.as_ref());
+        let method = match kind {
+            CallKind::Expect => format_expect_call(method, as_trait.as_ref()),
+            CallKind::OnCall => format_on_call(method, as_trait.as_ref()),
+            CallKind::Assert => format_assert_call(method, as_trait.as_ref()),
+        };
         let generics = as_trait
             .as_ref()
             .and_then(|t| t.segments.last())
@@ -129,24 +167,26 @@ impl ToTokens for Call {
                 }
             });
 
-            let mut arg_count = 0;
             let args = call_method
                 .then(|| Cow::Owned(Expr::Verbatim(quote!(#ident_murf :: matcher::any()))))
                 .into_iter()
                 .chain(args)
-                .inspect(|_| arg_count += 1)
                 .parenthesis();
 
-            if arg_count > 1 {
-                quote!(.with(#ident_murf :: matcher::multi(#args)))
-            } else {
-                quote!(.with(#args))
-            }
+            quote!(.with(#ident_murf :: matcher::multi(#args)))
         };
 
-        tokens.extend(quote! {
-            #obj.mock_handle().#method #turbofish().description(#desc)#args
-        });
+        if *kind == CallKind::Assert {
+            let times = times.as_ref().map(|times| quote!(.times(#times)));
+
+            tokens.extend(quote! {
+                #obj.mock_handle().#method #turbofish().description(#desc)#args #times .verify()
+            });
+        } else {
+            tokens.extend(quote! {
+                #obj.mock_handle().#method #turbofish().description(#desc)#args
+            });
+        }
 
         #[cfg(feature = "debug")]
         println!("\nexpect_call!:\n{tokens:#}\n");
@@ -16,7 +16,7 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(feature = "debug-to-file", feature(proc_macro_span))]
 
-use expect_call::CallMode;
+use expect_call::{CallKind, CallMode};
 use proc_macro::TokenStream;
 
 mod expect_call;
@@ -56,6 +56,101 @@ pub fn mock(input: TokenStream) -> TokenStream {
     mock::exec(input.into()).into()
 }
 
+/// Attribute macro to generate a mocked version of the annotated trait.
+///
+/// The trait itself is emitted unchanged. In addition a state type (named
+/// `Mocked{Trait}` by default) is generated that implements the trait, and the
+/// same mock types the [`mock!`] macro would generate for it. All generated
+/// items are only compiled with `#[cfg(test)]`.
+///
+/// The attribute accepts the following optional arguments:
+/// - an identifier to use as name of the generated state type
+/// - `derive(...)` to add additional derives to the state type (like `Send` or `Sync`)
+/// - `type Name = Type` to define the type of an associated type of the trait
+///
+/// # Example
+///
+/// ```
+/// #[automock(MyStruct, derive(Send))]
+/// trait Fuu {
+///     fn fuu(&self) -> usize;
+/// }
+///
+/// let (handle, mock) = MyStruct::mock_with_handle();
+///
+/// expect_method_call!(handle as Fuu, fuu()).will_once(Return(1));
+///
+/// assert_eq!(1, mock.fuu());
+/// ```
+#[proc_macro_attribute]
+#[cfg(not(doctest))]
+pub fn automock(attr: TokenStream, item: TokenStream) -> TokenStream {
+    mock::exec_automock(attr.into(), item.into()).into()
+}
+
+/// Macro to generate mocked versions of free functions.
+///
+/// For each passed function a dispatcher with the same signature is generated
+/// that forwards the call to the expectations of the mocked function. In
+/// addition a module with the same name as the function is generated, that
+/// contains the mocked types and a `handle()` function to create a new handle
+/// for the mocked function. If the function has a body, it is used as default
+/// action, and the body is executed directly as long as there is no expectation
+/// for the function in the current `LocalContext` (or in the global context if
+/// there is no local context). Functions without a body panic for any call
+/// that is not expected.
+///
+/// # Example
+///
+/// ```
+/// mock_fn! {
+///     pub fn read_register(addr: u32) -> u32;
+/// }
+///
+/// let handle = read_register::handle();
+///
+/// expect_call!(handle, read_register(eq(4))).will_once(Return(1));
+///
+/// assert_eq!(1, read_register(4));
+/// ```
+#[proc_macro]
+#[cfg(not(doctest))]
+pub fn mock_fn(input: TokenStream) -> TokenStream {
+    mock::exec_mock_fn(input.into()).into()
+}
+
+/// Attribute macro to generate a mocked version of the annotated function.
+///
+/// The function is emitted unchanged if `cfg(test)` is not set. For test builds
+/// the function is replaced by the same code the [`mock_fn!`] macro would
+/// generate for it. The original body is used as default action.
+///
+/// Calls are only checked against the expectations if there is at least one
+/// expectation for the function in the current `LocalContext` (or in the global
+/// context if there is no local context). Otherwise the original body is
+/// executed, so tests that do not define any expectation for the function are
+/// not affected by the mock.
+///
+/// # Example
+///
+/// ```
+/// #[mockable_fn]
+/// pub fn read_register(addr: u32) -> u32 {
+///     hal_read(addr)
+/// }
+///
+/// let handle = read_register::handle();
+///
+/// expect_call!(handle, read_register(eq(4))).will_once(Return(1));
+///
+/// assert_eq!(1, read_register(4));
+/// ```
+#[proc_macro_attribute]
+#[cfg(not(doctest))]
+pub fn mockable_fn(attr: TokenStream, item: TokenStream) -> TokenStream {
+    mock::exec_mockable_fn(attr.into(), item.into()).into()
+}
+
 /// Helper macro to define an call expectation of a specific function.
 ///
 /// # Example
@@ -68,7 +163,7 @@ pub fn mock(input: TokenStream) -> TokenStream {
 #[proc_macro]
 #[cfg(not(doctest))]
 pub fn expect_call(input: TokenStream) -> TokenStream {
-    expect_call::exec(input.into(), CallMode::Static).into()
+    expect_call::exec(input.into(), CallMode::Static, CallKind::Expect).into()
 }
 
 /// Helper macro to define an call expectation of a specific method. Same as
@@ -85,5 +180,71 @@ pub fn expect_call(input: TokenStream) -> TokenStream {
 #[proc_macro]
 #[cfg(not(doctest))]
 pub fn expect_method_call(input: TokenStream) -> TokenStream {
-    expect_call::exec(input.into(), CallMode::Method).into()
+    expect_call::exec(input.into(), CallMode::Method, CallKind::Expect).into()
+}
+
+/// Helper macro to define a default behavior of a specific function. The
+/// default behavior is used if no expectation matches a call to the function.
+///
+/// # Example
+///
+/// ```
+/// let (handle, mock) = MyStruct::mock_with_handle();
+///
+/// on_call!(handle as Fuu, fuu(_)).will_by_default(Return(1));
+/// ```
+#[proc_macro]
+#[cfg(not(doctest))]
+pub fn on_call(input: TokenStream) -> TokenStream {
+    expect_call::exec(input.into(), CallMode::Static, CallKind::OnCall).into()
+}
+
+/// Helper macro to define a default behavior of a specific method. Same as
+/// [`on_call!`] but will automatically add a `any` matcher for the `self`
+/// argument.
+///
+/// # Example
+///
+/// ```
+/// let (handle, mock) = MyStruct::mock_with_handle();
+///
+/// on_method_call!(handle as Fuu, fuu()).will_by_default(Return(1));
+/// ```
+#[proc_macro]
+#[cfg(not(doctest))]
+pub fn on_method_call(input: TokenStream) -> TokenStream {
+    expect_call::exec(input.into(), CallMode::Method, CallKind::OnCall).into()
+}
+
+/// Helper macro to check the recorded calls of a specific function after the
+/// code under test has been executed.
+///
+/// The arguments of the function are checked using the passed matchers. The
+/// `self` argument of methods is not passed to the matchers. By default the
+/// function is expected to be called at least once, use `times = ...` to
+/// define the expected number of matching calls. Only functions whose arguments
+/// are owned types or references to owned types (no generic types or
+/// `impl Trait`) can be checked, using the macro for any other function results
+/// in a compile error.
+///
+/// The values of the arguments are recorded as owned values, so the matchers
+/// receive references as the referenced type, `&str` as `String` and `&[T]` as
+/// `Vec<T>`. Arguments that do not implement `Clone` can not be recorded.
+///
+/// # Example
+///
+/// ```
+/// let (handle, mock) = MyStruct::mock_with_handle();
+///
+/// on_method_call!(handle as Fuu, fuu(_)).will_by_default(Return(1));
+///
+/// mock.fuu(3);
+/// mock.fuu(3);
+///
+/// assert_called!(handle as Fuu, fuu(eq(3)), times = 2);
+/// ```
+#[proc_macro]
+#[cfg(not(doctest))]
+pub fn assert_called(input: TokenStream) -> TokenStream {
+    expect_call::exec(input.into(), CallMode::Static, CallKind::Assert).into()
 }
@@ -1,4 +1,5 @@
-use lazy_static::lazy_static;
+use std::sync::LazyLock;
+
 use quote::ToTokens;
 use regex::{Captures, Regex};
 
@@ -19,7 +20,5 @@ where
     }
 }
 
-lazy_static! {
-    static ref PATH_FORMAT_1: Regex = Regex::new(r"\s*(<|>)\s*").unwrap();
-    static ref PATH_FORMAT_2: Regex = Regex::new(r"&\s*").unwrap();
-}
+static PATH_FORMAT_1: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s*(<|>)\s*").unwrap());
+static PATH_FORMAT_2: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"&\s*").unwrap());
@@ -18,7 +18,7 @@ where
 
         match count {
             0 => quote!(()),
-            1 => ret,
+            1 => quote!((#ret,)),
             _ => quote!((#ret)),
         }
     }
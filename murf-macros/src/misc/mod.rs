@@ -41,6 +41,60 @@ pub(crate) fn format_expect_call(method: &Ident, as_trait: Option<&Path>) -> Ide
     }
 }
 
+pub(crate) fn format_on_call(method: &Ident, as_trait: Option<&Path>) -> Ident {
+    if let Some(t) = as_trait {
+        format_ident!(
+            "as_{}_on_call_{}",
+            t.segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("_")
+                .replace(|c: char| !c.is_alphanumeric(), "_")
+                .to_case(Case::Snake),
+            method
+        )
+    } else {
+        format_ident!("on_call_{}", method.to_string())
+    }
+}
+
+pub(crate) fn format_assert_call(method: &Ident, as_trait: Option<&Path>) -> Ident {
+    if let Some(t) = as_trait {
+        format_ident!(
+            "as_{}_assert_{}",
+            t.segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("_")
+                .replace(|c: char| !c.is_alphanumeric(), "_")
+                .to_case(Case::Snake),
+            method
+        )
+    } else {
+        format_ident!("assert_{}", method.to_string())
+    }
+}
+
+pub(crate) fn format_calls_of(method: &Ident, as_trait: Option<&Path>) -> Ident {
+    if let Some(t) = as_trait {
+        format_ident!(
+            "as_{}_calls_{}",
+            t.segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("_")
+                .replace(|c: char| !c.is_alphanumeric(), "_")
+                .to_case(Case::Snake),
+            method
+        )
+    } else {
+        format_ident!("calls_{}", method.to_string())
+    }
+}
+
 pub(crate) fn format_expect_module(method: &Ident, as_trait: Option<&Path>) -> Ident {
     if let Some(t) = as_trait {
         format_ident!(
@@ -63,6 +117,10 @@ pub(crate) fn format_expectations_field(ident: &Ident) -> Ident {
     format_ident!("{}_expectations", ident)
 }
 
+pub(crate) fn format_on_calls_field(ident: &Ident) -> Ident {
+    format_ident!("{}_on_calls", ident)
+}
+
 #[cfg(feature = "force-name")]
 pub(crate) fn ident_murf() -> Ident {
     format_ident!("murf")
@@ -4,7 +4,10 @@ use std::{
 };
 
 use proc_macro2::{Ident, Span};
-use syn::{GenericArgument, Lifetime, Path, PathArguments, ReturnType, Type, TypeParamBound};
+use syn::{
+    GenericArgument, Lifetime, Path, PathArguments, ReturnType, Type, TypeImplTrait,
+    TypeParamBound, TypeTraitObject,
+};
 
 use super::TempLifetimes;
 
@@ -13,7 +16,7 @@ pub(crate) enum LifetimeReplaceMode<'x> {
     Temp(&'x mut TempLifetimes),
 }
 
-impl<'x> LifetimeReplaceMode<'x> {
+impl LifetimeReplaceMode<'_> {
     fn generate(&mut self) -> Lifetime {
         match self {
             Self::Mock => Lifetime::new("'mock", Span::call_site()),
@@ -24,6 +27,7 @@ impl<'x> LifetimeReplaceMode<'x> {
 
 pub(crate) trait TypeEx {
     fn contains_lifetime(&self, lt: &Lifetime) -> bool;
+    fn contains_lifetimes(&self) -> bool;
     fn contains_self_type(&self) -> bool;
 
     fn replace_self_type(self, type_: &Type, changed: &mut bool) -> Self;
@@ -39,7 +43,7 @@ impl TypeEx for Type {
             result: bool,
         }
 
-        impl<'a> TypeVisitor for Visitor<'a> {
+        impl TypeVisitor for Visitor<'_> {
             fn visit_lifetime(&mut self, lt: &UnsafeCell<Lifetime>) -> bool {
                 let lt = unsafe { &*lt.get() };
                 self.result = self.lt.ident == lt.ident || self.result;
@@ -55,6 +59,33 @@ impl TypeEx for Type {
         visitor.result
     }
 
+    fn contains_lifetimes(&self) -> bool {
+        struct Visitor {
+            result: bool,
+        }
+
+        impl TypeVisitor for Visitor {
+            fn visit_type(&mut self, ty: &UnsafeCell<Type>) -> bool {
+                let ty = unsafe { &*ty.get() };
+                self.result = matches!(ty, Type::Reference(_)) || self.result;
+
+                !self.result
+            }
+
+            fn visit_lifetime(&mut self, _lt: &UnsafeCell<Lifetime>) -> bool {
+                self.result = true;
+
+                false
+            }
+        }
+
+        let mut visitor = Visitor { result: false };
+
+        visitor.visit(unsafe_cell_ref(self));
+
+        visitor.result
+    }
+
     fn contains_self_type(&self) -> bool {
         struct Visitor {
             result: bool,
@@ -87,7 +118,7 @@ impl TypeEx for Type {
             changed: &'a mut bool,
         }
 
-        impl<'a> TypeVisitor for Visitor<'a> {
+        impl TypeVisitor for Visitor<'_> {
             fn visit_type(&mut self, ty: &UnsafeCell<Type>) -> bool {
                 let ty = unsafe { &mut *ty.get() };
 
@@ -114,7 +145,7 @@ impl TypeEx for Type {
             mode: LifetimeReplaceMode<'a>,
         }
 
-        impl<'a> TypeVisitor for Visitor<'a> {
+        impl TypeVisitor for Visitor<'_> {
             fn visit_type(&mut self, ty: &UnsafeCell<Type>) -> bool {
                 let ty = unsafe { &mut *ty.get() };
 
@@ -202,20 +233,18 @@ trait TypeVisitor: Sized {
                     PathArguments::AngleBracketed(x) => {
                         for arg in &x.args {
                             match arg {
-                                GenericArgument::Type(t) => {
-                                    if !this.visit(unsafe_cell_ref(t)) {
-                                        return false;
-                                    }
+                                GenericArgument::Type(t) if !this.visit(unsafe_cell_ref(t)) => {
+                                    return false;
                                 }
-                                GenericArgument::Lifetime(lt) => {
-                                    if !this.visit_lifetime(unsafe_cell_ref(lt)) {
-                                        return false;
-                                    }
+                                GenericArgument::Lifetime(lt)
+                                    if !this.visit_lifetime(unsafe_cell_ref(lt)) =>
+                                {
+                                    return false;
                                 }
-                                GenericArgument::AssocType(t) => {
-                                    if !this.visit(unsafe_cell_ref(&t.ty)) {
-                                        return false;
-                                    }
+                                GenericArgument::AssocType(t)
+                                    if !this.visit(unsafe_cell_ref(&t.ty)) =>
+                                {
+                                    return false;
                                 }
                                 _ => (),
                             }
@@ -275,18 +304,17 @@ trait TypeVisitor: Sized {
 
                 true
             }
-            Type::TraitObject(t) => {
-                for b in &t.bounds {
+            Type::TraitObject(TypeTraitObject { bounds, .. })
+            | Type::ImplTrait(TypeImplTrait { bounds, .. }) => {
+                for b in bounds {
                     match b {
-                        TypeParamBound::Lifetime(lt) => {
-                            if !self.visit_lifetime(unsafe_cell_ref(lt)) {
-                                return false;
-                            }
+                        TypeParamBound::Lifetime(lt)
+                            if !self.visit_lifetime(unsafe_cell_ref(lt)) =>
+                        {
+                            return false;
                         }
-                        TypeParamBound::Trait(t) => {
-                            if !visit_path(self, &t.path) {
-                                return false;
-                            }
+                        TypeParamBound::Trait(t) if !visit_path(self, &t.path) => {
+                            return false;
                         }
                         _ => (),
                     }
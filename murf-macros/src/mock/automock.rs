@@ -0,0 +1,246 @@
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse2, parse_quote,
+    punctuated::Punctuated,
+    token::{Comma, Paren},
+    Attribute, Error, File, Item, ItemTrait, Path, Result as ParseResult, Token, TraitItem,
+    TraitItemFn, TraitItemType, Type,
+};
+
+use super::{mocked::Mocked, parsed::Parsed};
+
+/// Parsed arguments of the `#[automock]` attribute.
+#[derive(Default)]
+pub(crate) struct AutomockArgs {
+    ident: Option<Ident>,
+    derives: Vec<Path>,
+    types: Vec<(Ident, Type)>,
+}
+
+enum AutomockArg {
+    Ident(Ident),
+    Derive(Punctuated<Path, Comma>),
+    Type(Ident, Box<Type>),
+}
+
+impl Parse for AutomockArg {
+    fn parse(input: ParseStream<'_>) -> ParseResult<Self> {
+        if input.peek(Token![type]) {
+            input.parse::<Token![type]>()?;
+            let ident = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let ty = input.parse::<Type>()?;
+
+            return Ok(Self::Type(ident, Box::new(ty)));
+        }
+
+        let ident = input.parse::<Ident>()?;
+        if ident == "derive" && input.peek(Paren) {
+            let content;
+            parenthesized!(content in input);
+
+            return Ok(Self::Derive(Punctuated::parse_terminated(&content)?));
+        }
+
+        Ok(Self::Ident(ident))
+    }
+}
+
+impl Parse for AutomockArgs {
+    fn parse(input: ParseStream<'_>) -> ParseResult<Self> {
+        let mut ret = Self::default();
+
+        for arg in Punctuated::<AutomockArg, Comma>::parse_terminated(input)? {
+            match arg {
+                AutomockArg::Ident(ident) if ret.ident.is_none() => ret.ident = Some(ident),
+                AutomockArg::Ident(ident) => {
+                    return Err(Error::new(
+                        ident.span(),
+                        "The name of the mocked type was already specified!",
+                    ))
+                }
+                AutomockArg::Derive(derives) => ret.derives.extend(derives),
+                AutomockArg::Type(ident, ty) => ret.types.push((ident, *ty)),
+            }
+        }
+
+        Ok(ret)
+    }
+}
+
+/// Generates the input of the [`mock!`](crate::mock!) macro from a trait
+/// definition annotated with `#[automock]`.
+pub(crate) struct Automock {
+    trait_: ItemTrait,
+    args: AutomockArgs,
+}
+
+impl Automock {
+    pub(crate) fn new(args: AutomockArgs, trait_: ItemTrait) -> Self {
+        Self { trait_, args }
+    }
+
+    fn mock_input(&self) -> ParseResult<TokenStream> {
+        let Self { trait_, args } = self;
+
+        let ident_trait = &trait_.ident;
+        let ident_state = args
+            .ident
+            .clone()
+            .unwrap_or_else(|| format_ident!("Mocked{}", ident_trait));
+        let vis = &trait_.vis;
+
+        let derives = args
+            .derives
+            .iter()
+            .filter(|p| !p.is_ident("Default"))
+            .collect::<Vec<_>>();
+
+        let (ga_impl, ga_types, ga_where) = trait_.generics.split_for_impl();
+
+        let mut items = Vec::new();
+        for item in &trait_.items {
+            match item {
+                TraitItem::Fn(TraitItemFn {
+                    attrs,
+                    sig,
+                    default,
+                    ..
+                }) => {
+                    let body = default
+                        .as_ref()
+                        .map_or_else(|| quote!(;), ToTokens::to_token_stream);
+
+                    let sig = replace_associated_types(sig.to_token_stream(), &args.types);
+
+                    items.push(quote! {
+                        #( #attrs )*
+                        #sig #body
+                    });
+                }
+                TraitItem::Type(TraitItemType {
+                    ident, generics, ..
+                }) => {
+                    let Some((_, ty)) = args.types.iter().find(|(i, _)| i == ident) else {
+                        let msg = format!("Missing type for associated type `{ident}`: use `#[automock(type {ident} = ...)]` to specify it!");
+
+                        return Err(Error::new(ident.span(), msg));
+                    };
+
+                    let (_, ga_types, ga_where) = generics.split_for_impl();
+
+                    items.push(quote! {
+                        type #ident #ga_types = #ty #ga_where;
+                    });
+                }
+                TraitItem::Const(c) if c.default.is_some() => (),
+                TraitItem::Const(c) => {
+                    return Err(Error::new(
+                        c.ident.span(),
+                        "Associated constants without default value are not supported by `#[automock]`!",
+                    ));
+                }
+                _ => (),
+            }
+        }
+
+        Ok(quote! {
+            #[derive(Default #( , #derives )*)]
+            #vis struct #ident_state;
+
+            impl #ga_impl #ident_trait #ga_types for #ident_state #ga_where {
+                #( #items )*
+            }
+        })
+    }
+}
+
+impl ToTokens for Automock {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let trait_ = &self.trait_;
+
+        let mocked = match self.mock_input().and_then(parse2::<Parsed>) {
+            Ok(parsed) => Mocked::new(parsed).into_token_stream(),
+            Err(err) => err.to_compile_error(),
+        };
+
+        let mut mocked = match parse2::<File>(mocked) {
+            Ok(file) => file,
+            Err(err) => {
+                tokens.extend(err.to_compile_error());
+
+                return;
+            }
+        };
+
+        for item in &mut mocked.items {
+            if let Some(attrs) = item_attrs_mut(item) {
+                attrs.insert(0, parse_quote!(#[cfg(test)]));
+            }
+        }
+
+        tokens.extend(quote! {
+            #trait_
+            #mocked
+        });
+    }
+}
+
+/// Replace all `Self::Name` paths of the passed `tokens` with the type that
+/// was specified for the associated type `Name`.
+fn replace_associated_types(tokens: TokenStream, types: &[(Ident, Type)]) -> TokenStream {
+    let mut ret = Vec::<TokenTree>::new();
+
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(g) => {
+                let mut group =
+                    Group::new(g.delimiter(), replace_associated_types(g.stream(), types));
+                group.set_span(g.span());
+
+                ret.push(TokenTree::Group(group));
+            }
+            TokenTree::Ident(ident) => {
+                let len = ret.len();
+                let ty = match &ret[len.saturating_sub(3)..] {
+                    [TokenTree::Ident(s), TokenTree::Punct(p1), TokenTree::Punct(p2)]
+                        if s == "Self" && p1.as_char() == ':' && p2.as_char() == ':' =>
+                    {
+                        types.iter().find(|(i, _)| *i == ident).map(|(_, ty)| ty)
+                    }
+                    _ => None,
+                };
+
+                if let Some(ty) = ty {
+                    ret.truncate(len - 3);
+                    ret.extend(quote!((#ty)));
+                } else {
+                    ret.push(TokenTree::Ident(ident));
+                }
+            }
+            tt => ret.push(tt),
+        }
+    }
+
+    ret.into_iter().collect()
+}
+
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(x) => Some(&mut x.attrs),
+        Item::Enum(x) => Some(&mut x.attrs),
+        Item::Fn(x) => Some(&mut x.attrs),
+        Item::Impl(x) => Some(&mut x.attrs),
+        Item::Macro(x) => Some(&mut x.attrs),
+        Item::Mod(x) => Some(&mut x.attrs),
+        Item::Static(x) => Some(&mut x.attrs),
+        Item::Struct(x) => Some(&mut x.attrs),
+        Item::Trait(x) => Some(&mut x.attrs),
+        Item::Type(x) => Some(&mut x.attrs),
+        Item::Use(x) => Some(&mut x.attrs),
+        _ => None,
+    }
+}
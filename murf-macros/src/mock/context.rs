@@ -4,16 +4,19 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use convert_case::{Case, Casing};
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::{format_ident, quote};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
-    token::Colon, FnArg, Generics, ImplItem, ImplItemFn, ItemImpl, Lifetime, Pat, PatType, Path,
-    ReturnType, Type,
+    parse_quote, token::Colon, Attribute, Error as ParseError, FnArg, Generics, ImplItem,
+    ImplItemFn, ItemImpl, Lifetime, Pat, PatType, Path, Result as ParseResult, ReturnType,
+    TraitBound, TraitBoundModifier, Type, TypeParamBound,
 };
 
 use crate::misc::{
-    format_expect_call, format_expect_module, format_expectations_field, ident_murf, AttribsEx,
-    GenericsEx, ItemImplEx, LifetimeReplaceMode, MethodEx, ReturnTypeEx, TempLifetimes, TypeEx,
+    format_assert_call, format_calls_of, format_expect_call, format_expect_module,
+    format_expectations_field, format_on_call, format_on_calls_field, ident_murf, AttribsEx,
+    FormattedString, GenericsEx, ItemImplEx, IterEx, LifetimeReplaceMode, MethodEx, ReturnTypeEx,
+    TempLifetimes, TypeEx,
 };
 
 use super::parsed::Parsed;
@@ -134,11 +137,22 @@ impl ImplContext {
         }
 
         let trait_ = impl_.trait_.as_ref().map(|(_, p, _)| p).cloned();
+        let async_trait = impl_
+            .attrs
+            .iter()
+            .find(|attr| {
+                attr.path()
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "async_trait")
+            })
+            .cloned();
 
         Self(Arc::new(ImplContextData {
             context,
 
             trait_,
+            async_trait,
 
             ga_impl,
             ga_impl_mock,
@@ -158,6 +172,7 @@ pub(crate) struct ImplContextData {
     pub context: Context,
 
     pub trait_: Option<Path>,
+    pub async_trait: Option<Attribute>,
 
     pub ga_impl: Generics,
     pub ga_impl_mock: Generics,
@@ -180,6 +195,7 @@ impl MethodContext {
     #[allow(clippy::too_many_lines)]
     pub(crate) fn new(context: ImplContext, impl_: &ItemImpl, method: &ImplItemFn) -> Self {
         let is_associated = method.is_associated_fn();
+        let is_async = method.sig.asyncness.is_some();
         let no_default_impl = method.has_murf_attr("no_default_impl");
 
         let (impl_, lts_temp) = impl_.clone().split_off_temp_lifetimes();
@@ -211,7 +227,8 @@ impl MethodContext {
                     pat: t.pat.clone(),
                     colon_token: t.colon_token,
                     ty: Box::new(
-                        t.ty.clone()
+                        erased_arg_type(&t.ty)
+                            .unwrap_or_else(|| (*t.ty).clone())
                             .replace_self_type(&type_mock, &mut has_self_arg),
                     ),
                 },
@@ -230,8 +247,29 @@ impl MethodContext {
             })
             .collect::<Vec<_>>();
 
+        let trait_send_action = is_associated
+            .then(|| quote!( + Send))
+            .or_else(|| context.trait_send.clone());
+        let lt_action = if is_associated {
+            quote!( + 'static)
+        } else {
+            quote!( + 'mock)
+        };
+
         let mut has_self_ret = false;
-        let return_type = ret.to_action_return_type(&type_mock, &mut has_self_ret);
+        let mut return_type = ret.to_action_return_type(&type_mock, &mut has_self_ret);
+        let impl_trait_bounds = impl_trait_bounds(&return_type);
+        if let Some(bounds) = &impl_trait_bounds {
+            return_type = erased_type(bounds, trait_send_action.as_ref(), &lt_action);
+        }
+
+        let action_return_type = if is_async {
+            Type::Verbatim(quote! {
+                ::std::pin::Pin<Box<dyn ::std::future::Future<Output = #return_type> #trait_send_action #lt_action>>
+            })
+        } else {
+            return_type.clone()
+        };
 
         let type_signature = args_prepared
             .iter()
@@ -241,9 +279,23 @@ impl MethodContext {
             .collect();
 
         let ident_method = method.sig.ident.clone();
+        let display_call = if let Some(t) = &trait_ {
+            format!(
+                "<{} as {}>::{}",
+                impl_.self_ty.to_token_stream(),
+                t.to_formatted_string(),
+                ident_method
+            )
+        } else {
+            format!("{}::{}", impl_.self_ty.to_token_stream(), ident_method)
+        };
         let ident_expect_method = format_expect_call(&ident_method, trait_.as_ref());
         let ident_expectation_module = format_expect_module(&ident_method, trait_.as_ref());
         let ident_expectation_field = format_expectations_field(&ident_expectation_module);
+        let ident_on_call_method = format_on_call(&ident_method, trait_.as_ref());
+        let ident_assert_method = format_assert_call(&ident_method, trait_.as_ref());
+        let ident_calls_method = format_calls_of(&ident_method, trait_.as_ref());
+        let ident_on_calls_field = format_on_calls_field(&ident_expectation_module);
 
         let mut ga_expectation = context
             .ga_impl
@@ -261,7 +313,7 @@ impl MethodContext {
                     .bounds
                     .push(Lifetime::new("'static", Span::call_site()));
             }
-        };
+        }
         let ga_expectation = ga_expectation.remove_lifetimes(&lts_temp);
 
         let mut ga_expectation_builder = ga_expectation
@@ -278,10 +330,20 @@ impl MethodContext {
                 .push(Lifetime::new("'static", Span::call_site()));
         }
 
+        let recorded_args = args
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Receiver(_) => None,
+                FnArg::Typed(t) => Some(recorded_arg_type(&t.ty, &ga_expectation)),
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|types| Type::Verbatim(types.parenthesis()));
+
         Self(Arc::new(MethodContextData {
             context,
 
             is_associated,
+            is_async,
             no_default_impl,
 
             impl_,
@@ -300,12 +362,20 @@ impl MethodContext {
             args_prepared,
             args_prepared_lt,
             return_type,
+            action_return_type,
+            impl_trait_bounds,
             type_signature,
+            recorded_args,
 
+            display_call,
             ident_method,
             ident_expect_method,
             ident_expectation_module,
             ident_expectation_field,
+            ident_on_call_method,
+            ident_on_calls_field,
+            ident_assert_method,
+            ident_calls_method,
         }))
     }
 }
@@ -322,6 +392,7 @@ pub(crate) struct MethodContextData {
     pub context: ImplContext,
 
     pub is_associated: bool,
+    pub is_async: bool,
     pub no_default_impl: bool,
 
     pub impl_: ItemImpl,
@@ -340,12 +411,20 @@ pub(crate) struct MethodContextData {
     pub args_prepared: Vec<PatType>,
     pub args_prepared_lt: Vec<PatType>,
     pub return_type: Type,
+    pub action_return_type: Type,
+    pub impl_trait_bounds: Option<Vec<TypeParamBound>>,
     pub type_signature: Vec<Type>,
+    pub recorded_args: Option<Type>,
 
+    pub display_call: String,
     pub ident_method: Ident,
     pub ident_expect_method: Ident,
     pub ident_expectation_module: Ident,
     pub ident_expectation_field: Ident,
+    pub ident_on_call_method: Ident,
+    pub ident_on_calls_field: Ident,
+    pub ident_assert_method: Ident,
+    pub ident_calls_method: Ident,
 }
 
 impl Deref for MethodContextData {
@@ -355,3 +434,363 @@ impl Deref for MethodContextData {
         &self.context
     }
 }
+
+/* Helper */
+
+/// Get the trait bounds of the passed type if it is a `impl Trait` type.
+///
+/// The only lifetime bound that is part of the result is `'static`, because
+/// any other lifetime is not known outside of the method signature. Use
+/// [`impl_trait_lifetime`] to get them.
+pub(crate) fn impl_trait_bounds(ty: &Type) -> Option<Vec<TypeParamBound>> {
+    let Type::ImplTrait(ty) = ty else {
+        return None;
+    };
+
+    Some(
+        ty.bounds
+            .iter()
+            .filter(|b| match b {
+                TypeParamBound::Trait(_) => true,
+                TypeParamBound::Lifetime(lt) => lt.ident == "static",
+                _ => false,
+            })
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Get the lifetime bound of the passed type if it is a `impl Trait` type that
+/// has one (like `impl Iterator<Item = &str> + 'a`).
+pub(crate) fn impl_trait_lifetime(ty: &Type) -> Option<Lifetime> {
+    let Type::ImplTrait(ty) = ty else {
+        return None;
+    };
+
+    ty.bounds.iter().find_map(|b| match b {
+        TypeParamBound::Lifetime(lt) => Some(lt.clone()),
+        _ => None,
+    })
+}
+
+/// Checks if the bounds of the passed `impl Trait` return type are supported.
+///
+/// The returned value is stored as `Box<dyn Trait>` (or `Pin<Box<dyn Trait>>`
+/// for futures and streams), so only traits that are implemented for these
+/// boxed types are supported, and only one of them may be used (additional
+/// auto traits like `Send` are fine).
+pub(crate) fn check_impl_trait_return(ty: &Type) -> ParseResult<()> {
+    let Type::ImplTrait(ty) = ty else {
+        return Ok(());
+    };
+
+    let mut main_trait = None;
+    let mut lifetime = None;
+
+    for bound in &ty.bounds {
+        match bound {
+            TypeParamBound::Trait(t) => match erased_trait_kind(t) {
+                Some(ErasedTraitKind::Auto) => (),
+                Some(_) if main_trait.is_some() => {
+                    return Err(ParseError::new_spanned(
+                        t,
+                        "Only one non-auto trait is supported for `impl Trait` return types!",
+                    ))
+                }
+                Some(_) => main_trait = Some(t),
+                None => {
+                    return Err(ParseError::new_spanned(
+                        t,
+                        format!(
+                        "Unsupported bound `{}` for `impl Trait` return type! Supported are: {}",
+                        t.to_formatted_string(),
+                        SUPPORTED_ERASED_TRAITS
+                    ),
+                    ))
+                }
+            },
+            TypeParamBound::Lifetime(lt) if lifetime.is_some() => {
+                return Err(ParseError::new_spanned(
+                    lt,
+                    "Only one lifetime bound is supported for `impl Trait` return types!",
+                ))
+            }
+            TypeParamBound::Lifetime(lt) => lifetime = Some(lt),
+            bound => {
+                return Err(ParseError::new_spanned(
+                    bound,
+                    "Unsupported bound for `impl Trait` return type!",
+                ))
+            }
+        }
+    }
+
+    if main_trait.is_none() {
+        return Err(ParseError::new_spanned(
+            ty,
+            "`impl Trait` return types need at least one non-auto trait bound!",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks if the bounds of the passed `impl Trait` argument type (or
+/// `&impl Trait`, `&mut impl Trait`) are supported.
+///
+/// The argument is passed to the matchers and actions as `&dyn Trait` (see
+/// [`erased_arg_type`]), so only traits that can be used as trait objects are
+/// supported. Traits like `Into<T>`, `AsRef<T>` or `IntoIterator` are rejected,
+/// because their methods take `self` by value or are generic.
+pub(crate) fn check_impl_trait_arg(ty: &Type) -> ParseResult<()> {
+    let ty = match ty {
+        Type::ImplTrait(ty) => ty,
+        Type::Reference(r) => match &*r.elem {
+            Type::ImplTrait(ty) => ty,
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    for bound in &ty.bounds {
+        match bound {
+            TypeParamBound::Trait(t) if erased_trait_kind(t).is_none() => {
+                return Err(ParseError::new_spanned(
+                    t,
+                    format!(
+                        "Unsupported bound `{}` for `impl Trait` argument! Use a generic type parameter instead, or one of: {}",
+                        t.to_formatted_string(),
+                        SUPPORTED_ERASED_TRAITS
+                    ),
+                ))
+            }
+            TypeParamBound::Trait(_) | TypeParamBound::Lifetime(_) => (),
+            bound => {
+                return Err(ParseError::new_spanned(
+                    bound,
+                    "Unsupported bound for `impl Trait` argument!",
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if a `impl Trait` type with the passed `bounds` needs to be
+/// pinned to be usable (like futures or streams), `false` otherwise.
+pub(crate) fn is_pinned(bounds: &[TypeParamBound]) -> bool {
+    bounds.iter().any(|b| {
+        matches!(b, TypeParamBound::Trait(t) if erased_trait_kind(t) == Some(ErasedTraitKind::Pinned))
+    })
+}
+
+/// Get the type that is used to store a value of a `impl Trait` type with the
+/// passed `bounds`.
+///
+/// Futures and streams are stored as `Pin<Box<dyn Trait>>`, anything else as
+/// `Box<dyn Trait>`. The passed lifetime `lt` is only used if `bounds` does not
+/// already contain a lifetime bound.
+pub(crate) fn erased_type(
+    bounds: &[TypeParamBound],
+    trait_send: Option<&TokenStream>,
+    lt: &TokenStream,
+) -> Type {
+    let lt = bounds
+        .iter()
+        .all(|b| !matches!(b, TypeParamBound::Lifetime(_)))
+        .then_some(lt);
+
+    if is_pinned(bounds) {
+        Type::Verbatim(quote!(::std::pin::Pin<Box<dyn #( #bounds )+* #trait_send #lt>>))
+    } else {
+        Type::Verbatim(quote!(Box<dyn #( #bounds )+* #trait_send #lt>))
+    }
+}
+
+/// How a trait of a `impl Trait` return type is erased.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErasedTraitKind {
+    /// Auto trait that is just added to the erased type.
+    Auto,
+
+    /// Trait that is implemented by `Box<dyn Trait>`.
+    Boxed,
+
+    /// Trait that is implemented by `Pin<Box<dyn Trait>>`.
+    Pinned,
+}
+
+/// List of the supported traits for `impl Trait` return types (used for error
+/// messages).
+const SUPPORTED_ERASED_TRAITS: &str = "`Iterator`, `DoubleEndedIterator`, `ExactSizeIterator`, \
+    `FusedIterator`, `Fn`, `FnMut`, `FnOnce`, `Debug`, `Display`, `Read`, `Write`, \
+    `BufRead`, `Seek`, `Future`, `Stream`, `FusedStream`, `Send`, `Sync` and `Unpin`";
+
+/// Get the [`ErasedTraitKind`] of the passed trait bound, or `None` if the
+/// trait is not supported.
+///
+/// Traits are identified by name, so the path of the trait must either be a
+/// single identifier or start with one of the crates that define the trait
+/// (`std`, `core`, `alloc`, `futures`, `futures_core` or `futures_util`).
+fn erased_trait_kind(bound: &TraitBound) -> Option<ErasedTraitKind> {
+    const CRATES: &[&str] = &[
+        "std",
+        "core",
+        "alloc",
+        "futures",
+        "futures_core",
+        "futures_util",
+    ];
+
+    if !matches!(bound.modifier, TraitBoundModifier::None) {
+        return None;
+    }
+
+    let segments = &bound.path.segments;
+    let first = segments.first()?;
+    if segments.len() > 1 && !CRATES.iter().any(|c| first.ident == c) {
+        return None;
+    }
+
+    match segments.last()?.ident.to_string().as_str() {
+        "Send" | "Sync" | "Unpin" => Some(ErasedTraitKind::Auto),
+        "Iterator"
+        | "DoubleEndedIterator"
+        | "ExactSizeIterator"
+        | "FusedIterator"
+        | "Fn"
+        | "FnMut"
+        | "FnOnce"
+        | "Debug"
+        | "Display"
+        | "Read"
+        | "Write"
+        | "BufRead"
+        | "Seek" => Some(ErasedTraitKind::Boxed),
+        "Future" | "Stream" | "FusedStream" => Some(ErasedTraitKind::Pinned),
+        _ => None,
+    }
+}
+
+/// Get the type that is used to record the value of an argument of the passed
+/// type in the call history of a mocked object, or `None` if the argument can
+/// not be recorded.
+///
+/// References are recorded as the referenced type (`&str` as `String`, `&[T]`
+/// as `Vec<T>`, `&Path` as `PathBuf`, ..., see `OwnedArg`). The resulting type
+/// must be an owned type (see [`is_owned_type`]).
+pub(crate) fn recorded_arg_type(ty: &Type, generics: &Generics) -> Option<Type> {
+    let ty = match ty {
+        Type::Reference(r) => match &*r.elem {
+            Type::Path(p) if p.qself.is_none() => {
+                let ident = &p.path.segments.last()?.ident;
+
+                if p.path.is_ident("str") {
+                    parse_quote!(String)
+                } else if ident == "Path" {
+                    parse_quote!(::std::path::PathBuf)
+                } else if ident == "OsStr" {
+                    parse_quote!(::std::ffi::OsString)
+                } else if ident == "CStr" {
+                    parse_quote!(::std::ffi::CString)
+                } else {
+                    Type::Path(p.clone())
+                }
+            }
+            Type::Slice(s) => {
+                let elem = &s.elem;
+
+                parse_quote!(Vec<#elem>)
+            }
+            elem => elem.clone(),
+        },
+        ty => ty.clone(),
+    };
+
+    is_owned_type(&ty, generics).then_some(ty)
+}
+
+/// Returns `true` if the passed type is an owned type that does not depend on
+/// any lifetime, trait object, `Self` or any of the type parameters of
+/// `generics`, `false` otherwise.
+fn is_owned_type(ty: &Type, generics: &Generics) -> bool {
+    fn check(tokens: TokenStream, params: &[&Ident]) -> bool {
+        tokens.into_iter().all(|t| match t {
+            TokenTree::Group(g) => check(g.stream(), params),
+            TokenTree::Punct(p) => p.as_char() != '&' && p.as_char() != '\'',
+            TokenTree::Ident(i) => {
+                i != "impl" && i != "dyn" && i != "Self" && !params.contains(&&i)
+            }
+            TokenTree::Literal(_) => true,
+        })
+    }
+
+    let params = generics.type_params().map(|p| &p.ident).collect::<Vec<_>>();
+
+    check(ty.to_token_stream(), &params)
+}
+
+/// Get the type that is used to pass an argument of type `impl Trait` (or
+/// `&impl Trait`, `&mut impl Trait`) to the matchers and actions of an
+/// expectation. Returns `None` if the passed type does not need to be erased.
+///
+/// `impl Trait` and `&impl Trait` are passed as `&dyn Trait`, `&mut impl Trait`
+/// is passed as `&mut dyn Trait`.
+pub(crate) fn erased_arg_type(ty: &Type) -> Option<Type> {
+    match ty {
+        Type::ImplTrait(t) => {
+            let bounds = &t.bounds;
+
+            Some(parse_quote!(&(dyn #bounds)))
+        }
+        Type::Reference(r) => {
+            let Type::ImplTrait(t) = &*r.elem else {
+                return None;
+            };
+
+            let bounds = &t.bounds;
+            let lt = &r.lifetime;
+            let mutability = &r.mutability;
+
+            Some(parse_quote!(& #lt #mutability (dyn #bounds)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, Type};
+
+    use super::check_impl_trait_arg;
+
+    #[test]
+    fn dyn_compatible_impl_trait_arg() {
+        let types: [Type; 4] = [
+            parse_quote!(impl Fn(u8) + Send),
+            parse_quote!(&impl std::fmt::Debug),
+            parse_quote!(&mut impl Write),
+            parse_quote!(Vec<u8>),
+        ];
+
+        for ty in &types {
+            assert!(check_impl_trait_arg(ty).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejected_impl_trait_arg() {
+        let types: [Type; 4] = [
+            parse_quote!(impl Into<String>),
+            parse_quote!(impl AsRef<str>),
+            parse_quote!(impl IntoIterator<Item = u8>),
+            parse_quote!(&impl Clone),
+        ];
+
+        for ty in &types {
+            let err = check_impl_trait_arg(ty).unwrap_err();
+            assert!(err.to_string().starts_with("Unsupported bound `"), "{err}");
+        }
+    }
+}
@@ -71,7 +71,7 @@ impl ToTokens for Expectation {
             args_prepared,
             args_prepared_lt,
 
-            return_type,
+            action_return_type,
             type_signature,
             ..
         } = &**context;
@@ -119,7 +119,7 @@ impl ToTokens for Expectation {
                 pub description: Option<String>,
 
                 /// Action that is executed once the actual call to the mocked method is made.
-                pub action: Option<Box<dyn #lts_mock RepeatableAction<#arg_types_prepared_lt, #return_type> #trait_send #trait_sync #lt>>,
+                pub action: Option<Box<dyn #lts_mock RepeatableAction<#arg_types_prepared_lt, #action_return_type> #trait_send #trait_sync #lt>>,
 
                 /// Matcher that is used to verify the arguments of the call.
                 pub matcher: Option<Box<dyn #lts_mock Matcher<#arg_types_prepared_lt> #trait_send #trait_sync #lt>>,
@@ -127,6 +127,15 @@ impl ToTokens for Expectation {
                 /// List of sequences the expectation must respect.
                 pub sequences: Vec<SequenceHandle>,
 
+                /// List of expectations that must be satisfied before this expectation is active.
+                pub after: Vec<ExpectationRef>,
+
+                /// Reference that is used to track the state of this expectation.
+                pub reference: ExpectationRef,
+
+                /// Whether the expectation is retired once the upper bound of [`times`](Self::times) is reached.
+                pub retires_on_saturation: bool,
+
                 _marker: #ga_expectation_phantom,
             }
 
@@ -138,6 +147,9 @@ impl ToTokens for Expectation {
                         action: None,
                         matcher: None,
                         sequences: Vec::new(),
+                        after: Vec::new(),
+                        reference: ExpectationRef::new(),
+                        retires_on_saturation: false,
                         _marker: PhantomData,
                     }
                 }
@@ -151,6 +163,9 @@ impl ToTokens for Expectation {
                         .field("action", &self.action.is_some())
                         .field("matcher", &self.matcher.is_some())
                         .field("sequences", &self.sequences)
+                        .field("after", &self.after)
+                        .field("reference", &self.reference)
+                        .field("retires_on_saturation", &self.retires_on_saturation)
                         .finish()
                 }
             }
@@ -167,6 +182,14 @@ impl ToTokens for Expectation {
                         true
                     }
                 }
+
+                /// Notify the matcher that the expectation was selected to handle the call
+                /// whose arguments were passed to the last call of [`matches`](Self::matches).
+                pub fn commit_match(&self) {
+                    if let Some(m) = &self.matcher {
+                        m.commit();
+                    }
+                }
             }
 
             impl #ga_expectation_impl #ident_murf :: Expectation for Expectation #ga_expectation_types #ga_expectation_where {
@@ -178,12 +201,24 @@ impl ToTokens for Expectation {
                     self.times.is_ready()
                 }
 
+                fn is_retired(&self) -> bool {
+                    self.reference.is_retired() || (self.retires_on_saturation && self.times.is_done())
+                }
+
                 fn set_done(&self) {
                     for seq_handle in &self.sequences {
                         seq_handle.set_done();
                     }
                 }
 
+                fn times(&self) -> &Times {
+                    &self.times
+                }
+
+                fn sequences(&self) -> &[SequenceHandle] {
+                    &self.sequences
+                }
+
                 fn type_signature(&self) -> &'static str {
                     type_name::<#type_signature>()
                 }
@@ -7,7 +7,7 @@ use syn::ImplItemFn;
 use crate::misc::{IterEx, MethodEx, TempLifetimes};
 
 use super::{
-    context::{ContextData, MethodContext, MethodContextData},
+    context::{is_pinned, ContextData, MethodContext, MethodContextData},
     parsed::Parsed,
 };
 
@@ -33,12 +33,16 @@ impl ToTokens for ExpectationBuilder {
         let MethodContextData {
             context,
             is_associated,
+            is_async,
             ident_expectation_field,
+            ident_on_calls_field,
+            ident_on_call_method,
             ga_expectation,
             ga_expectation_builder,
             lts_mock: TempLifetimes(lts_mock),
             args_prepared_lt,
             return_type,
+            impl_trait_bounds,
             ..
         } = &**context;
 
@@ -66,6 +70,87 @@ impl ToTokens for ExpectationBuilder {
 
         let arg_types_prepared_lt = args_prepared_lt.iter().map(|t| &t.ty).parenthesis();
 
+        let impl_trait_bounds = impl_trait_bounds.as_ref().filter(|_| !is_async);
+
+        let (will_generics, will_where, action_return_type) =
+            if let Some(bounds) = impl_trait_bounds {
+                (
+                    quote!(<A, R>),
+                    Some(quote!(R: #( #bounds )+* #trait_send #lt,)),
+                    quote!(R),
+                )
+            } else {
+                (quote!(<A>), None, return_type.to_token_stream())
+            };
+
+        let (once_action, repeated_action) = if *is_async {
+            (
+                quote!(#ident_murf :: action::ReadyAction::new(OnetimeAction::new(action))),
+                quote!(#ident_murf :: action::ReadyAction::new(RepeatedAction::new(action))),
+            )
+        } else if let Some(bounds) = impl_trait_bounds {
+            let boxed = if is_pinned(bounds) {
+                quote!(Box::pin(ret))
+            } else {
+                quote!(Box::new(ret))
+            };
+
+            (
+                quote!(#ident_murf :: action::MapAction::new(OnetimeAction::new(action), |ret: R| -> #return_type { #boxed })),
+                quote!(#ident_murf :: action::MapAction::new(RepeatedAction::new(action), |ret: R| -> #return_type { #boxed })),
+            )
+        } else {
+            (
+                quote!(OnetimeAction::new(action)),
+                quote!(RepeatedAction::new(action)),
+            )
+        };
+
+        let async_actions = is_async.then(|| {
+            quote! {
+                /// Specify an asynchronous action that should be executed once the actual call to the linked method was made.
+                ///
+                /// The action is executed when the method is called, the returned future is awaited by the caller.
+                /// This will set `.times(1)` before the action is added. I you want to use repeatedly executed
+                /// actions please have a look at [`will_repeatedly_async`](Self::will_repeatedly_async).
+                pub fn will_once_async<A, F>(self, action: A) -> ExpectationRef
+                where
+                    A: #lts_mock Action<#arg_types_prepared_lt, F> #trait_send #trait_sync #lt,
+                    F: ::std::future::Future<Output = #return_type> #trait_send #lt,
+                {
+                    let mut this = self.times(1);
+                    this.expectation().action = Some(Box::new(#ident_murf :: action::FutureAction::new(OnetimeAction::new(action))));
+
+                    this.expectation_ref()
+                }
+
+                /// Specify an asynchronous action that should be executed each time a call to the linked method was made.
+                pub fn will_repeatedly_async<A, F>(mut self, action: A) -> ExpectationRef
+                where
+                    A: #lts_mock Action<#arg_types_prepared_lt, F> #trait_send #trait_sync + Clone #lt,
+                    F: ::std::future::Future<Output = #return_type> #trait_send #lt,
+                {
+                    self.expectation().action = Some(Box::new(#ident_murf :: action::FutureAction::new(RepeatedAction::new(action))));
+
+                    self.expectation_ref()
+                }
+            }
+        });
+
+        let on_call_async_actions = is_async.then(|| {
+            quote! {
+                /// Specify an asynchronous action that should be executed for each matching call
+                /// that is not handled by any expectation.
+                pub fn will_by_default_async<A, F>(mut self, action: A)
+                where
+                    A: #lts_mock Action<#arg_types_prepared_lt, F> #trait_send #trait_sync + Clone #lt,
+                    F: ::std::future::Future<Output = #return_type> #trait_send #lt,
+                {
+                    self.expectation().action = Some(Box::new(#ident_murf :: action::FutureAction::new(RepeatedAction::new(action))));
+                }
+            }
+        });
+
         let drop_handler = if *is_associated {
             quote! {
                 let expectation: Box<dyn #ident_murf :: Expectation + Send + Sync + 'static> = Box::new(expectation);
@@ -161,23 +246,57 @@ impl ToTokens for ExpectationBuilder {
                     self
                 }
 
+                /// Add an expectation that must be satisfied before this expectation is active.
+                ///
+                /// This can be called multiple times to define more than one prerequisite.
+                /// Prerequisites that are retired do no longer block this expectation.
+                pub fn after(mut self, expectation: &ExpectationRef) -> Self {
+                    self.expectation().after.push(expectation.clone());
+
+                    self
+                }
+
+                /// Retire the expectation once the upper bound of its call count is reached.
+                ///
+                /// A retired expectation is no longer considered if the linked method is called, so
+                /// expectations that were defined before this one become reachable again.
+                pub fn retires_on_saturation(mut self) -> Self {
+                    self.expectation().retires_on_saturation = true;
+
+                    self
+                }
+
                 /// Specify an action that should be executed once the actual call to the linked method was made.
                 ///
                 /// This will set `.times(1)` before the action is added. I you want to use
                 /// repeatedly executed actions please have a look at [`will_repeatedly`](Self::will_repeatedly).
-                pub fn will_once<A>(self, action: A)
+                pub fn will_once #will_generics (self, action: A) -> ExpectationRef
                 where
-                    A: #lts_mock Action<#arg_types_prepared_lt, #return_type> #trait_send #trait_sync #lt,
+                    A: #lts_mock Action<#arg_types_prepared_lt, #action_return_type> #trait_send #trait_sync #lt,
+                    #will_where
                 {
-                    self.times(1).expectation().action = Some(Box::new(OnetimeAction::new(action)));
+                    let mut this = self.times(1);
+                    this.expectation().action = Some(Box::new(#once_action));
+
+                    this.expectation_ref()
                 }
 
                 /// Specify an action that should be executed each time a call to the linked method was made.
-                pub fn will_repeatedly<A>(mut self, action: A)
+                pub fn will_repeatedly #will_generics (mut self, action: A) -> ExpectationRef
                 where
-                    A: #lts_mock Action<#arg_types_prepared_lt, #return_type> #trait_send #trait_sync + Clone #lt,
+                    A: #lts_mock Action<#arg_types_prepared_lt, #action_return_type> #trait_send #trait_sync + Clone #lt,
+                    #will_where
                 {
-                    self.expectation().action = Some(Box::new(RepeatedAction::new(action)));
+                    self.expectation().action = Some(Box::new(#repeated_action));
+
+                    self.expectation_ref()
+                }
+
+                #async_actions
+
+                /// Get a [`ExpectationRef`] that references the expectation of this builder.
+                pub fn expectation_ref(&mut self) -> ExpectationRef {
+                    self.expectation().reference.clone()
                 }
 
                 fn expectation(&mut self) -> &mut Expectation #ga_expectation_types {
@@ -198,6 +317,9 @@ impl ToTokens for ExpectationBuilder {
                 fn drop(&mut self) {
                     if let Some(expectation) = self.expectation.take() {
                         let desc = expectation.to_string();
+                        expectation.reference.set_description(desc.clone());
+                        expectation.reference.update(&expectation.times);
+
                         for seq_handle in &expectation.sequences {
                             seq_handle.set_description(desc.clone());
 
@@ -215,5 +337,88 @@ impl ToTokens for ExpectationBuilder {
                 }
             }
         });
+
+        if *is_associated {
+            return;
+        }
+
+        let on_call_doc = format!(
+            "Helper type that is used to define a default behavior (see [`Handle::{ident_on_call_method}`](super::Handle::{ident_on_call_method})) for the linked method."
+        );
+
+        tokens.extend(quote! {
+            #[doc = #on_call_doc]
+            ///
+            /// A default behavior is only used if no expectation matches the call. It is never
+            /// verified by [`checkpoint`](super::Handle::checkpoint) and does not affect any
+            /// call count or sequence.
+            #[must_use = "You need to define an action for this default behavior!"]
+            pub struct OnCallBuilder #ga_expectation_builder_impl #ga_expectation_builder_where {
+                handle: &'mock_exp Handle #ga_handle_types,
+                expectation: Option<Expectation #ga_expectation_types>,
+            }
+
+            impl #ga_expectation_builder_impl OnCallBuilder #ga_expectation_builder_types #ga_expectation_builder_where {
+                /// Create a new [`OnCallBuilder`] object
+                pub fn new(handle: &'mock_exp Handle #ga_handle_types,) -> Self {
+                    let mut expectation = Expectation::default();
+                    expectation.times.range = (..).into();
+
+                    Self {
+                        handle,
+                        expectation: Some(expectation),
+                    }
+                }
+
+                /// Add a description to the default behavior.
+                pub fn description<S: Into<String>>(mut self, value: S) -> Self {
+                    self.expectation().description = Some(value.into());
+
+                    self
+                }
+
+                /// Add a [`Matcher`] to the default behavior.
+                ///
+                /// The default behavior is only used for calls that matches the matcher.
+                pub fn with<M: #lts_mock Matcher<#arg_types_prepared_lt> #trait_send #trait_sync #lt>(mut self, matcher: M) -> Self {
+                    self.expectation().matcher = Some(Box::new(matcher));
+
+                    self
+                }
+
+                /// Specify an action that should be executed for each matching call
+                /// that is not handled by any expectation.
+                pub fn will_by_default #will_generics (mut self, action: A)
+                where
+                    A: #lts_mock Action<#arg_types_prepared_lt, #action_return_type> #trait_send #trait_sync + Clone #lt,
+                    #will_where
+                {
+                    self.expectation().action = Some(Box::new(#repeated_action));
+                }
+
+                #on_call_async_actions
+
+                fn expectation(&mut self) -> &mut Expectation #ga_expectation_types {
+                    self.expectation.as_mut().unwrap()
+                }
+            }
+
+            impl #ga_expectation_builder_impl Debug for OnCallBuilder #ga_expectation_builder_types #ga_expectation_builder_where {
+                fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                    f.debug_struct("OnCallBuilder")
+                        .field("handle", &self.handle)
+                        .field("expectation", self.expectation.as_ref().unwrap())
+                        .finish()
+                }
+            }
+
+            impl #ga_expectation_builder_impl Drop for OnCallBuilder #ga_expectation_builder_types #ga_expectation_builder_where {
+                fn drop(&mut self) {
+                    if let Some(expectation) = self.expectation.take().filter(|ex| ex.action.is_some()) {
+                        self.handle.shared.lock().#ident_on_calls_field.push(Box::new(expectation));
+                    }
+                }
+            }
+        });
     }
 }
@@ -13,6 +13,7 @@ pub(crate) struct ExpectationModule {
     pub context: MethodContext,
     pub expectation: Expectation,
     pub expectation_builder: ExpectationBuilder,
+    pub original: Option<TokenStream>,
 }
 
 impl ExpectationModule {
@@ -21,6 +22,7 @@ impl ExpectationModule {
         parsed: &Parsed,
         impl_: &ItemImpl,
         method: &ImplItemFn,
+        original: Option<TokenStream>,
     ) -> Self {
         let expectation = Expectation::new(context.clone(), impl_);
         let expectation_builder = ExpectationBuilder::new(context.clone(), parsed, method);
@@ -29,6 +31,7 @@ impl ExpectationModule {
             context,
             expectation,
             expectation_builder,
+            original,
         }
     }
 }
@@ -39,6 +42,7 @@ impl ToTokens for ExpectationModule {
             context,
             expectation,
             expectation_builder,
+            original,
         } = self;
 
         let MethodContextData {
@@ -46,6 +50,7 @@ impl ToTokens for ExpectationModule {
             ident_method,
             is_associated,
             ident_expectation_module,
+            recorded_args,
             ..
         } = &**context;
 
@@ -58,11 +63,27 @@ impl ToTokens for ExpectationModule {
                 pub fn cleanup_associated_expectations() {
                     EXPECTATIONS.lock().retain_mut(|ex| ex.strong_count() > 0);
                 }
+
+                /// Returns `true` if there is at least one expectation for the function in the
+                /// current local context (or in the global context if there is no local context),
+                /// `false` otherwise.
+                pub fn has_expectations() -> bool {
+                    match #ident_murf :: LocalContext::current().borrow().as_ref() {
+                        Some(local) => local.expectations(*TYPE_ID).any(|ex| ex.strong_count() > 0),
+                        None => EXPECTATIONS.lock().iter().any(|ex| ex.strong_count() > 0),
+                    }
+                }
             })
         } else {
             None
         };
 
+        let assert_called_supported = (recorded_args.is_some() && !is_associated).then(|| {
+            quote! {
+                impl #ident_murf :: misc::AssertCalledSupported for Method { }
+            }
+        });
+
         let type_ = if let Some(trait_) = trait_ {
             trait_.into_token_stream().to_string()
         } else {
@@ -78,7 +99,7 @@ impl ToTokens for ExpectationModule {
                 use std::fmt::{Display, Formatter, Result as FmtResult};
 
                 use #ident_murf :: {
-                    Matcher, Times, TimesRange, Sequence, SequenceHandle, InSequence,
+                    Matcher, Times, TimesRange, Sequence, SequenceHandle, InSequence, ExpectationRef,
                     action::{Action, RepeatableAction, OnetimeAction, RepeatedAction},
                 };
 
@@ -87,11 +108,24 @@ impl ToTokens for ExpectationModule {
 
                 #expectation
                 #expectation_builder
+                #original
 
                 #associated_expectations
 
                 /// Unique ID that represents this type of expectation.
                 pub static TYPE_ID: Lazy<usize> = Lazy::new(#ident_murf :: next_type_id);
+
+                /// Marker type that identifies the mocked method.
+                #[derive(Debug)]
+                pub struct Method;
+
+                impl #ident_murf :: MockedMethod for Method {
+                    fn type_id() -> usize {
+                        *TYPE_ID
+                    }
+                }
+
+                #assert_called_supported
             }
         });
     }
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::Generics;
+use syn::{parse_quote, Generics};
 
 use crate::misc::GenericsEx;
 
@@ -27,18 +27,26 @@ impl Handle {
         self.methods.push(context);
     }
 
+    #[allow(clippy::too_many_lines)]
     fn render_method(context: &MethodContextData) -> TokenStream {
         let MethodContextData {
             trait_,
             is_associated,
             ident_method,
             ident_expect_method,
+            ident_on_call_method,
+            ident_assert_method,
+            ident_calls_method,
             ident_expectation_module,
+            recorded_args,
+            display_call,
             ga_method,
             ga_expectation,
             ..
         } = context;
 
+        let ident_murf = &context.ident_murf;
+
         let mut ga_builder = ga_expectation.clone();
         if *is_associated {
             ga_builder = ga_builder.add_lifetime("'mock");
@@ -62,6 +70,86 @@ Returns an [`ExpectationBuilder`]({ident_expectation_module}::ExpectationBuilder
 can be used to specialize the expectation further."
         );
 
+        /* associated functions are not bound to a mock object, so they do not support default behaviors */
+        let on_call = (!is_associated).then(|| {
+            let doc = format!(
+                r"Add a new default behavior for the [`{type_}::{ident_method}`]({type_}::{ident_method}) method to the mocked object.
+
+The default behavior is used if no expectation matches a call to the method.
+It does not affect the result of [`checkpoint`](Self::checkpoint).
+
+# Returns
+Returns an [`OnCallBuilder`]({ident_expectation_module}::OnCallBuilder) that
+can be used to specialize the default behavior further."
+            );
+
+            quote! {
+                #[doc = #doc]
+                pub fn #ident_on_call_method #ga_method_impl(&self) -> #ident_expectation_module::OnCallBuilder #ga_builder_types
+                #ga_method_where
+                {
+                    #ident_expectation_module::OnCallBuilder::new(self)
+                }
+            }
+        });
+
+        /* associated functions are not bound to a mock object, so their calls are not recorded */
+        let calls = (!is_associated).then(|| {
+            let doc = format!(
+                r"Get the recorded calls to the [`{type_}::{ident_method}`]({type_}::{ident_method}) method of the mocked object."
+            );
+
+            quote! {
+                #[doc = #doc]
+                pub fn #ident_calls_method #ga_method_impl(&self) -> Vec<#ident_murf :: CallRecord>
+                #ga_method_where
+                {
+                    self.calls_of::<#ident_expectation_module::Method>()
+                }
+            }
+        });
+
+        /* only methods with owned arguments (or references to them) are recorded in a way they can be checked later on,
+         * for all other methods the assertion is rejected when it is used (see `AssertCalledSupported`) */
+        let assert = (!is_associated).then(|| {
+            let doc = format!(
+                r"Create a new assertion for the recorded calls to the [`{type_}::{ident_method}`]({type_}::{ident_method}) method of the mocked object.
+
+The arguments of the calls are recorded as owned values (references as the
+referenced type, `&str` as `String` and `&[T]` as `Vec<T>`) unless this was
+disabled using [`set_record_args`](Self::set_record_args).
+
+# Returns
+Returns a `CallAssertion` that can be used to check the recorded calls."
+            );
+
+            if let Some(recorded_args) = recorded_args {
+                quote! {
+                    #[doc = #doc]
+                    pub fn #ident_assert_method #ga_method_impl(&self) -> #ident_murf :: CallAssertion<#recorded_args>
+                    #ga_method_where
+                    {
+                        #ident_murf :: CallAssertion::new(#display_call, self.calls_of::<#ident_expectation_module::Method>())
+                    }
+                }
+            } else {
+                let mut ga_unsupported = ga_method.clone();
+                ga_unsupported.make_where_clause().predicates.push(parse_quote! {
+                    for<'x> #ident_expectation_module::Method: #ident_murf :: misc::AssertCalledSupported
+                });
+                let (ga_unsupported_impl, _ga_unsupported_types, ga_unsupported_where) = ga_unsupported.split_for_impl();
+
+                quote! {
+                    #[doc = #doc]
+                    pub fn #ident_assert_method #ga_unsupported_impl(&self) -> #ident_murf :: CallAssertion<()>
+                    #ga_unsupported_where
+                    {
+                        #ident_murf :: CallAssertion::new(#display_call, self.calls_of::<#ident_expectation_module::Method>())
+                    }
+                }
+            }
+        });
+
         quote! {
             #[doc = #doc]
             pub fn #ident_expect_method #ga_method_impl(&self) -> #ident_expectation_module::ExpectationBuilder #ga_builder_types
@@ -69,11 +157,18 @@ can be used to specialize the expectation further."
             {
                 #ident_expectation_module::ExpectationBuilder::new(self)
             }
+
+            #on_call
+
+            #calls
+
+            #assert
         }
     }
 }
 
 impl ToTokens for Handle {
+    #[allow(clippy::too_many_lines)]
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let Self {
             context,
@@ -81,7 +176,14 @@ impl ToTokens for Handle {
             ga_handle_extra,
         } = self;
 
-        let ContextData { ga_handle, .. } = &**context;
+        let ContextData {
+            ident_murf,
+            ident_state,
+            ga_handle,
+            ..
+        } = &**context;
+
+        let object = format!("Mocked object '{ident_state}'");
 
         let (ga_handle_impl, ga_handle_types, ga_handle_where) = ga_handle.split_for_impl();
         let (ga_handle_extra_impl, ga_handle_extra_types, ga_handle_extra_where) =
@@ -113,13 +215,115 @@ impl ToTokens for Handle {
                 ///
                 /// Panics if at least one expectation was nof fulfilled.
                 pub fn checkpoint(&self) {
+                    if let Err(report) = self.try_checkpoint() {
+                        println!();
+                        println!("{report}");
+                        panic!("{}", report.title());
+                    }
+                }
+
+                /// Check if all expectations has been fulfilled without raising a panic.
+                ///
+                /// # Errors
+                ///
+                /// Returns a `VerificationReport` that contains all unfulfilled expectations if at
+                /// least one expectation was not fulfilled. If the handle could not be locked
+                /// (because it is already locked by the current thread), the returned report is
+                /// marked as `locked`.
+                pub fn try_checkpoint(&self) -> Result<(), #ident_murf :: VerificationReport> {
                     if let Some(mut locked) = self.shared.try_lock() {
-                        locked.checkpoint();
+                        locked.checkpoint()
                     } else {
-                        panic!("Unable to lock handle: Deadlock? Make sure that you do not drop a handle (or call `checkpoint` directly) of the same object inside an action of an expectation.");
+                        Err(#ident_murf :: VerificationReport::locked(#object))
                     }
                 }
 
+                /// Set the policy that is used for calls of methods without any expectation
+                /// (uninteresting calls).
+                pub fn set_policy(&self, policy: #ident_murf :: Policy) {
+                    self.shared.lock().policy = policy;
+                }
+
+                /// Get the policy that is used for calls of methods without any expectation
+                /// (uninteresting calls).
+                pub fn policy(&self) -> #ident_murf :: Policy {
+                    self.shared.lock().policy
+                }
+
+                /// Set the order the expectations are checked in if a mocked method is called.
+                pub fn set_match_order(&self, match_order: #ident_murf :: MatchOrder) {
+                    self.shared.lock().match_order = match_order;
+                }
+
+                /// Get the order the expectations are checked in if a mocked method is called.
+                pub fn match_order(&self) -> #ident_murf :: MatchOrder {
+                    self.shared.lock().match_order
+                }
+
+                /// Get the calls that were made on the mocked object.
+                ///
+                /// Calls to associated functions are not bound to the mocked object, so they
+                /// are not recorded.
+                pub fn calls(&self) -> Vec<#ident_murf :: CallRecord> {
+                    self.shared.lock().calls.iter().cloned().collect()
+                }
+
+                /// Get the calls that were made to the mocked method identified by `M`.
+                ///
+                /// Use the generated accessors like `as_fuu_calls_fuu` to get the calls of a
+                /// specific method instead.
+                #[doc(hidden)]
+                pub fn calls_of<M: #ident_murf :: MockedMethod>(&self) -> Vec<#ident_murf :: CallRecord> {
+                    let type_id = M::type_id();
+
+                    self.shared
+                        .lock()
+                        .calls
+                        .iter()
+                        .filter(|call| call.type_id == type_id)
+                        .cloned()
+                        .collect()
+                }
+
+                /// Remove all recorded calls of the mocked object.
+                pub fn clear_calls(&self) {
+                    self.shared.lock().calls.clear();
+                }
+
+                /// Enable or disable the recording of the calls that are made on the mocked
+                /// object (enabled by default).
+                pub fn set_record_calls(&self, enabled: bool) {
+                    self.shared.lock().calls.set_enabled(enabled);
+                }
+
+                /// Set the maximum number of calls that are recorded (`None` for no limit).
+                /// The default is `CallHistory::DEFAULT_CAPACITY`. If the limit is reached,
+                /// the oldest record is dropped for each new call.
+                pub fn set_call_history_capacity(&self, capacity: Option<usize>) {
+                    self.shared.lock().calls.set_capacity(capacity);
+                }
+
+                /// Enable or disable the recording of the argument values of the calls that
+                /// are made on the mocked object (enabled by default).
+                ///
+                /// The values are needed to check the arguments of the calls using
+                /// `assert_called!`. To record the values, the arguments are cloned for each
+                /// call, which can be avoided by disabling this.
+                pub fn set_record_args(&self, enabled: bool) {
+                    self.shared.lock().calls.set_record_args(enabled);
+                }
+
+                /// Get the faults that were injected into the calls of the mocked object
+                /// (see the fault injection actions like `fail_nth`).
+                pub fn faults(&self) -> Vec<#ident_murf :: Fault> {
+                    self.shared.lock().faults.faults()
+                }
+
+                /// Get the number of faults that were injected into the calls of the mocked object.
+                pub fn fault_count(&self) -> usize {
+                    self.shared.lock().faults.count()
+                }
+
                 /// Returns a reference to itself.
                 ///
                 /// This is used to make the public API of the handle compatible to the mock object.
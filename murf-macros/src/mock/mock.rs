@@ -34,6 +34,7 @@ impl ToTokens for Mock {
         let Self { context, impls } = self;
 
         let ContextData {
+            ident_murf,
             ident_state,
             ga_state,
             ga_mock,
@@ -74,6 +75,27 @@ impl ToTokens for Mock {
                     }
                 }
 
+                impl #ga_mock_impl Mock #ga_mock_types #ga_mock_where {
+                    /// Create a new empty mock object that silently forwards calls of methods
+                    /// without any expectation to the default implementation of the state.
+                    pub fn nice() -> Self {
+                        Self::new().mock_with_policy(#ident_murf :: Policy::Nice)
+                    }
+
+                    /// Create a new empty mock object that forwards calls of methods without any
+                    /// expectation to the default implementation of the state and prints a
+                    /// warning.
+                    pub fn naggy() -> Self {
+                        Self::new().mock_with_policy(#ident_murf :: Policy::Naggy)
+                    }
+
+                    /// Create a new empty mock object that panics for calls of methods without
+                    /// any expectation.
+                    pub fn strict() -> Self {
+                        Self::new().mock_with_policy(#ident_murf :: Policy::Strict)
+                    }
+                }
+
                 impl #ga_mock_impl Default for Mock #ga_mock_types #ga_mock_where {
                     fn default() -> Self {
                         Self::new()
@@ -86,6 +108,7 @@ impl ToTokens for Mock {
             let Impl { context, items } = impl_;
             let ImplContextData {
                 trait_,
+                async_trait,
                 ga_impl_mock,
                 ..
             } = &**context;
@@ -95,6 +118,7 @@ impl ToTokens for Mock {
             let (ga_impl, _ga_types, ga_where) = ga_impl_mock.split_for_impl();
 
             quote! {
+                #async_trait
                 impl #ga_impl #trait_ Mock #ga_mock_types #ga_where {
                     #( #items )*
                 }
@@ -165,6 +189,14 @@ impl ToTokens for Mock {
                     }
                 }
 
+                /// Set the policy that is used for calls of methods without any expectation
+                /// (uninteresting calls).
+                pub fn mock_with_policy(self, policy: #ident_murf :: Policy) -> Self {
+                    self.shared.lock().policy = policy;
+
+                    self
+                }
+
                 /// Release the handle of this mock object. See [`release`](Handle::release()) for details.
                 ///
                 /// # Panics
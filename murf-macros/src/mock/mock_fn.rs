@@ -0,0 +1,220 @@
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse2, Attribute, Block, Error, FnArg, GenericParam, Pat, Result as ParseResult, Signature,
+    Token, Visibility,
+};
+
+use crate::misc::{format_expect_module, AttribsEx};
+
+use super::{mocked::Mocked, parsed::Parsed};
+
+/// List of free functions that should be mocked by the `mock_fn!` macro.
+pub(crate) struct MockFns(Vec<MockFn>);
+
+impl Parse for MockFns {
+    fn parse(input: ParseStream<'_>) -> ParseResult<Self> {
+        let mut ret = Vec::new();
+
+        while !input.is_empty() {
+            ret.push(input.parse()?);
+        }
+
+        Ok(Self(ret))
+    }
+}
+
+impl ToTokens for MockFns {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for f in &self.0 {
+            f.to_tokens(tokens);
+        }
+    }
+}
+
+/// Free function that should be mocked.
+///
+/// The function is implemented as associated function of a state type that is
+/// mocked using the same code as the [`mock!`](crate::mock!) macro. The actual
+/// function is replaced by a dispatcher that forwards the call to the mocked
+/// associated function.
+pub(crate) struct MockFn {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    sig: Signature,
+    block: Option<Block>,
+
+    cfg_test: bool,
+}
+
+impl MockFn {
+    /// Only emit the mocked function if `cfg(test)` is set.
+    pub(crate) fn cfg_test(mut self) -> Self {
+        self.cfg_test = true;
+
+        self
+    }
+
+    fn mock_input(&self) -> TokenStream {
+        let Self {
+            attrs, sig, block, ..
+        } = self;
+
+        let ident_state = self.ident_state();
+        let block = block
+            .as_ref()
+            .map_or_else(|| quote!(;), ToTokens::to_token_stream);
+
+        quote! {
+            #[derive(Default)]
+            pub struct #ident_state;
+
+            impl #ident_state {
+                #( #attrs )*
+                pub #sig #block
+            }
+        }
+    }
+
+    fn ident_state(&self) -> Ident {
+        format_ident!("{}", self.sig.ident.to_string().to_case(Case::UpperCamel))
+    }
+}
+
+impl Parse for MockFn {
+    fn parse(input: ParseStream<'_>) -> ParseResult<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse::<Visibility>()?;
+        let sig = input.parse::<Signature>()?;
+        let block = if input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+
+            None
+        } else {
+            Some(input.parse::<Block>()?)
+        };
+
+        for arg in &sig.inputs {
+            match arg {
+                FnArg::Receiver(r) => {
+                    return Err(Error::new_spanned(
+                        r,
+                        "Mocked functions must not have a `self` argument!",
+                    ))
+                }
+                FnArg::Typed(t) if !matches!(&*t.pat, Pat::Ident(_)) => {
+                    return Err(Error::new_spanned(
+                        &t.pat,
+                        "Only simple identifiers are supported as arguments of mocked functions!",
+                    ))
+                }
+                FnArg::Typed(_) => (),
+            }
+        }
+
+        Ok(Self {
+            attrs,
+            vis,
+            sig,
+            block,
+            cfg_test: false,
+        })
+    }
+}
+
+impl ToTokens for MockFn {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            attrs,
+            vis,
+            sig,
+            cfg_test,
+            ..
+        } = self;
+
+        let mocked = match parse2::<Parsed>(self.mock_input()) {
+            Ok(parsed) => Mocked::new(parsed).into_token_stream(),
+            Err(err) => err.to_compile_error(),
+        };
+
+        let ident = &sig.ident;
+        let ident_state = self.ident_state();
+        let ident_mock = format_ident!("{}Mock", ident_state);
+        let ident_handle = format_ident!("{}Handle", ident_state);
+
+        let cfg_test = cfg_test.then(|| quote!(#[cfg(test)]));
+        let attrs = attrs.clone().remove_murf_attrs();
+
+        let mut sig_dispatch = sig.clone();
+        let mut args = Vec::new();
+        for arg in &mut sig_dispatch.inputs {
+            if let FnArg::Typed(t) = arg {
+                if let Pat::Ident(p) = &mut *t.pat {
+                    p.by_ref = None;
+                    p.mutability = None;
+
+                    args.push(p.ident.clone());
+                }
+            }
+        }
+
+        let generics = sig
+            .generics
+            .params
+            .iter()
+            .filter_map(|p| match p {
+                GenericParam::Type(t) => Some(&t.ident),
+                GenericParam::Const(c) => Some(&c.ident),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let turbofish = (!generics.is_empty()).then(|| quote!(::< #( #generics ),* >));
+        let await_ = sig.asyncness.map(|_| quote!(.await));
+
+        let doc = format!("Mocked version of the [`{ident}`](fn@{ident}) function.");
+
+        /* functions with a body are executed unchanged if there is no expectation for them,
+         * so tests that do not care about the function are not affected by the mock */
+        let call_mock = quote!(#ident::#ident_mock::#ident #turbofish ( #( #args ),* ) #await_);
+        let dispatch = if self.block.is_some() {
+            let ident_module =
+                format_ident!("mock_impl_{}", ident_state.to_string().to_case(Case::Snake));
+            let ident_expectation_module = format_expect_module(ident, None);
+
+            quote! {
+                if #ident::#ident_module::#ident_expectation_module::has_expectations() {
+                    #call_mock
+                } else {
+                    #ident::#ident_state::#ident #turbofish ( #( #args ),* ) #await_
+                }
+            }
+        } else {
+            call_mock
+        };
+
+        tokens.extend(quote! {
+            #cfg_test
+            #( #attrs )*
+            #vis #sig_dispatch {
+                #dispatch
+            }
+
+            #cfg_test
+            #[doc = #doc]
+            #vis mod #ident {
+                #[allow(clippy::wildcard_imports)]
+                use super::*;
+
+                #mocked
+
+                /// Create a new handle that can be used to define expectations for the
+                /// mocked function.
+                pub fn handle() -> #ident_handle<'static> {
+                    #ident_handle::new()
+                }
+            }
+        });
+    }
+}
@@ -1,40 +1,69 @@
+use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{FnArg, ImplItemFn, Item, PatType, ReturnType, Stmt, Type};
+use syn::{
+    parse_quote, FnArg, GenericParam, Generics, ImplItemFn, Item, Meta, PatType, ReturnType,
+    Signature, Stmt, Type, WherePredicate,
+};
 
-use crate::misc::{AttribsEx, FormattedString, IterEx, TypeEx};
+use crate::misc::{
+    AttribsEx, FormattedString, GenericsEx, IterEx, LifetimeReplaceMode, TempLifetimes, TypeEx,
+};
 
-use super::context::{MethodContext, MethodContextData};
+use super::context::{
+    erased_arg_type, erased_type, impl_trait_bounds, impl_trait_lifetime, is_pinned, MethodContext,
+    MethodContextData,
+};
 
 pub(crate) struct MockMethod;
 
 impl MockMethod {
+    /// Render the mocked method. Returns the method and the implementation of
+    /// `OriginalCall` for its arguments (if the method supports calling the
+    /// original implementation), which needs to be part of the expectation module.
     #[allow(clippy::too_many_lines)]
-    pub(crate) fn render(context: &MethodContext, mut method: ImplItemFn) -> ImplItemFn {
+    pub(crate) fn render(
+        context: &MethodContext,
+        mut method: ImplItemFn,
+    ) -> (ImplItemFn, Option<TokenStream>) {
         let MethodContextData {
             is_associated,
+            is_async,
             no_default_impl,
             impl_,
             trait_,
             ga_expectation,
+            ga_expectation_builder,
             ident_expectation_module,
             ident_expectation_field,
+            ident_on_calls_field,
             args,
             ret,
             args_prepared,
+            args_prepared_lt,
+            action_return_type,
+            lts_mock: TempLifetimes(lts_mock),
             type_signature,
+            recorded_args,
             ..
         } = &**context;
 
         let ident_murf = &context.ident_murf;
+        let async_trait = &context.async_trait;
 
         let locked = if *is_associated {
             quote! {
                 let locked = #ident_expectation_module::EXPECTATIONS.lock();
+                let match_order = #ident_murf :: LocalContext::current()
+                    .borrow()
+                    .as_ref()
+                    .map_or_else(#ident_murf :: MatchOrder::default, #ident_murf :: local_context::Inner::match_order);
             }
         } else {
             quote! {
                 let shared = self.shared.clone();
-                let mut locked = shared.lock();
+                let mut guard = shared.lock();
+                let locked = &mut *guard;
+                let match_order = locked.match_order;
             }
         };
 
@@ -51,6 +80,57 @@ impl MockMethod {
             quote!(&mut locked.#ident_expectation_field)
         };
 
+        let call = &context.display_call;
+
+        /* calls to associated functions are not bound to a mock object, so they are not recorded */
+        let record_call = (!is_associated).then(|| {
+            let arg_pats = args
+                .iter()
+                .filter_map(|arg| match arg {
+                    FnArg::Receiver(_) => None,
+                    FnArg::Typed(t) => Some(&t.pat),
+                })
+                .collect::<Vec<_>>();
+            /* there is nothing to clone for methods without arguments, so they are always captured */
+            let values = match recorded_args {
+                Some(recorded_args) if arg_pats.is_empty() => {
+                    quote!((&&#ident_murf :: misc::CaptureArgs::<_, #recorded_args>::new(&())).capture_args())
+                }
+                Some(recorded_args) => quote! {
+                    if locked.calls.records_args() {
+                        (&&#ident_murf :: misc::CaptureArgs::<_, #recorded_args>::new(&( #( &#arg_pats, )* ))).capture_args()
+                    } else {
+                        None
+                    }
+                },
+                None => quote!(None),
+            };
+
+            quote! {
+                let call_index = if locked.calls.is_enabled() {
+                    #[allow(unused_imports)]
+                    use #ident_murf :: misc::{CaptureArgsClone as _, CaptureArgsFallback as _, DebugArgFallback as _, DebugArgFormat as _};
+
+                    let record = #ident_murf :: CallRecord::new(
+                        *#ident_expectation_module::TYPE_ID,
+                        #call,
+                        vec![ #( (&&#ident_murf :: misc::DebugArg(&#arg_pats)).format_arg() ),* ],
+                    );
+
+                    locked.calls.push(record.with_values(#values))
+                } else {
+                    None
+                };
+            }
+        });
+        let record_match = (!is_associated).then(|| {
+            quote! {
+                if let Some(record) = call_index.and_then(|i| locked.calls.get_mut(i)) {
+                    record.expectation = Some(ex.to_string());
+                }
+            }
+        });
+
         let expectation_unwrap = is_associated.then(|| {
             quote! {
                 let ex = if let Some(ex) = ex.upgrade() {
@@ -69,12 +149,28 @@ impl MockMethod {
             .iter()
             .map(|arg| match arg {
                 FnArg::Receiver(_) => quote!(self),
-                FnArg::Typed(PatType { pat, .. }) => quote!( #pat ),
+                FnArg::Typed(PatType { pat, ty, .. }) => match (&**ty, erased_arg_type(ty)) {
+                    (Type::Reference(r), Some(erased)) if r.mutability.is_some() => {
+                        quote!(&mut *#pat as #erased)
+                    }
+                    (Type::Reference(_), Some(erased)) => quote!(&*#pat as #erased),
+                    (_, Some(erased)) => quote!(&#pat as #erased),
+                    (_, None) => quote!( #pat ),
+                },
             })
             .parenthesis();
 
         let type_signature = type_signature.parenthesis();
-        let arg_names_prepared = args_prepared.iter().map(|arg| &arg.pat).parenthesis();
+
+        /* erased arguments are passed to the default action using the original value */
+        let arg_names_prepared = args_prepared
+            .iter()
+            .zip(args)
+            .map(|(arg, orig)| match orig {
+                FnArg::Typed(t) if erased_arg_type(&t.ty).is_some() => quote!(_),
+                _ => arg.pat.to_token_stream(),
+            })
+            .parenthesis();
 
         let default_args = method.sig.inputs.iter().map(|i| match i {
             FnArg::Receiver(r) if r.ty.to_formatted_string() == "Pin<&mut Self>" => {
@@ -95,7 +191,7 @@ impl MockMethod {
         });
 
         let default_action = if *no_default_impl {
-            quote!(panic!("No default implementation for expectation {}", ex))
+            quote!(panic!("No default implementation for expectation {ex}"))
         } else if let Some(t) = trait_ {
             let ident = &method.sig.ident;
             let self_ty = &impl_.self_ty;
@@ -120,8 +216,18 @@ impl MockMethod {
             }
         };
 
+        let ret_impl_trait_bounds = match ret {
+            ReturnType::Type(_, t) => impl_trait_bounds(t),
+            ReturnType::Default => None,
+        };
+
         let result = match ret {
             _ if *no_default_impl => None,
+            _ if *is_async => Some(Self::render_async_result(ret, call)),
+            _ if ret_impl_trait_bounds.as_ref().is_some_and(|b| is_pinned(b)) => {
+                Some(quote!(Box::pin(ret)))
+            }
+            _ if ret_impl_trait_bounds.is_some() => Some(quote!(Box::new(ret))),
             ReturnType::Default => Some(quote!(ret)),
             ReturnType::Type(_, t) => Some(match &**t {
                 Type::Reference(r)
@@ -142,40 +248,303 @@ impl MockMethod {
                 }>),
                 t if t.contains_self_type() => {
                     let s = format!(
-                        "No default implementation for `{}` for expectation {{}}",
+                        "No default implementation for `{}` for {call}",
                         t.to_formatted_string()
                     );
 
-                    quote!(panic!(#s, ex))
+                    quote!(panic!(#s))
                 }
                 _ => quote!(ret),
             }),
         };
 
-        let error = if let Some(t) = trait_ {
-            format!(
-                "<{} as {}>",
-                impl_.self_ty.to_token_stream(),
-                t.to_formatted_string()
-            )
+        /* the original implementation can only be called by the `call_original` action if
+         * the default action does only depend on the passed arguments and its result does
+         * not borrow from them, it is implemented for the type of the arguments */
+        let has_erased_args = args
+            .iter()
+            .any(|arg| matches!(arg, FnArg::Typed(t) if erased_arg_type(&t.ty).is_some()));
+        let has_borrowed_ret = matches!(ret, ReturnType::Type(_, t) if t.contains_self_type() || t.contains_lifetimes());
+        let has_original = !is_associated
+            && !is_async
+            && !no_default_impl
+            && !has_erased_args
+            && !has_borrowed_ret
+            && ret_impl_trait_bounds.is_none();
+        let original = has_original.then(|| {
+            let ga_original = lts_mock
+                .iter()
+                .fold(ga_expectation.clone(), |ga, lt| ga.add_lifetime(&lt.to_string()));
+            let (ga_original_impl, _ga_original_types, ga_original_where) =
+                ga_original.split_for_impl();
+            let arg_types_prepared_lt = args_prepared_lt.iter().map(|t| &t.ty).parenthesis();
+            let (ga_builder_impl, ga_builder_types, ga_builder_where) =
+                ga_expectation_builder.split_for_impl();
+
+            quote! {
+                impl #ga_original_impl #ident_murf :: action::OriginalCall<Expectation #ga_expectation_types, #action_return_type>
+                    for #arg_types_prepared_lt
+                #ga_original_where
+                {
+                    fn call_original(self) -> #action_return_type {
+                        let args = self;
+
+                        #default_action
+                        #result
+                    }
+                }
+
+                impl #ga_builder_impl ExpectationBuilder #ga_builder_types #ga_builder_where {
+                    /// Forward the next call to the linked method to its original implementation
+                    /// (see the `call_original` action).
+                    ///
+                    /// This will set `.times(1)` like [`will_once`](Self::will_once).
+                    pub fn will_call_original(self) -> ExpectationRef {
+                        self.will_once(#ident_murf :: action::call_original::<Expectation #ga_expectation_types>())
+                    }
+
+                    /// Forward each call to the linked method to its original implementation
+                    /// (see the `call_original` action).
+                    pub fn will_repeatedly_call_original(self) -> ExpectationRef {
+                        self.will_repeatedly(#ident_murf :: action::call_original::<Expectation #ga_expectation_types>())
+                    }
+                }
+
+                impl #ga_builder_impl OnCallBuilder #ga_builder_types #ga_builder_where {
+                    /// Forward each matching call that is not handled by any expectation to
+                    /// the original implementation of the linked method.
+                    pub fn will_by_default_call_original(self) {
+                        self.will_by_default(#ident_murf :: action::call_original::<Expectation #ga_expectation_types>());
+                    }
+                }
+            }
+        });
+
+        /* associated functions are executed in place, for any other method the action is
+         * detached (if possible) so the lock of the shared state can be released while the
+         * action is executed; faults injected into calls to associated functions are not
+         * recorded */
+        let exec_args = if *is_associated {
+            quote!(action.exec(args))
+        } else {
+            quote! {{
+                let _faults = locked.faults.enter(#call);
+
+                if let Some(action) = action.detach() {
+                    drop(guard);
+
+                    action.exec_boxed(args)
+                } else {
+                    action.exec(args)
+                }
+            }}
+        };
+
+        let error = format!("No suitable expectation found for {call}");
+        let uninteresting =
+            format!("Uninteresting call to {call}: falling back to the default implementation");
+
+        let trait_send = is_associated
+            .then(|| quote!( + Send))
+            .or_else(|| context.trait_send.clone());
+        let boxed_return_type = if *is_async {
+            let output = match &method.sig.output {
+                ReturnType::Default => quote!(()),
+                ReturnType::Type(_, t) => t.to_token_stream(),
+            };
+
+            method.sig.asyncness = None;
+
+            if let Some(async_trait) = async_trait {
+                let is_local = matches!(&async_trait.meta, Meta::List(l) if l.tokens.to_string().replace(' ', "") == "?Send");
+                let future_send = (!is_local).then(|| quote!( + Send));
+
+                Self::expand_async_trait_signature(&mut method.sig, &impl_.generics);
+
+                method.sig.output = parse_quote! {
+                    -> ::std::pin::Pin<Box<dyn ::std::future::Future<Output = #output> #future_send + 'async_trait>>
+                };
+
+                Some(Type::Verbatim(quote! {
+                    ::std::pin::Pin<Box<dyn ::std::future::Future<Output = #output> #future_send + 'async_trait>>
+                }))
+            } else {
+                method.sig.output = parse_quote!(-> impl ::std::future::Future<Output = #output>);
+
+                Some(Type::Verbatim(quote! {
+                    ::std::pin::Pin<Box<dyn ::std::future::Future<Output = #output> #trait_send + '_>>
+                }))
+            }
+        } else {
+            let lt = match ret {
+                ReturnType::Type(_, t) => impl_trait_lifetime(t),
+                ReturnType::Default => None,
+            };
+            let lt = lt.map_or_else(|| quote!( + '_), |lt| quote!( + #lt));
+
+            ret_impl_trait_bounds
+                .as_ref()
+                .map(|bounds| erased_type(bounds, trait_send.as_ref(), &lt))
+        };
+
+        let wrap_return = |value: TokenStream| {
+            if let Some(boxed_return_type) = &boxed_return_type {
+                quote! {
+                    let ret: #boxed_return_type = #value;
+
+                    return ret;
+                }
+            } else {
+                quote! {
+                    return #value;
+                }
+            }
+        };
+
+        /* default behaviors (`on_call`) are only supported for non-associated methods,
+         * the newest matching one is used */
+        let find_on_call = (!is_associated).then(|| {
+            quote! {
+                let on_call = locked.#ident_on_calls_field.iter_mut().rev().find_map(|on_call| {
+                    if on_call.type_signature() != type_name::<#type_signature>() {
+                        return None;
+                    }
+
+                    let on_call: &mut dyn #ident_murf :: Expectation = &mut **on_call;
+                    #[allow(clippy::cast_ptr_alignment)]
+                    let on_call = unsafe { &mut *(std::ptr::from_mut::<dyn #ident_murf :: Expectation>(on_call).cast::<#ident_expectation_module::Expectation #ga_expectation_types>()) };
+
+                    on_call.matches(&args).then_some(on_call)
+                });
+
+                if let Some(on_call) = &on_call {
+                    on_call.commit_match();
+                }
+            }
+        });
+
+        /* the default implementation of non-associated methods is executed without holding
+         * the lock of the shared state */
+        let release_lock = (!is_associated && !no_default_impl).then(|| quote!(drop(guard);));
+
+        let default = if find_on_call.is_some() {
+            quote! {
+                #find_on_call
+
+                if let Some(action) = on_call.and_then(|on_call| on_call.action.as_mut()) {
+                    #exec_args
+                } else {
+                    #release_lock
+                    #default_action
+                    #result
+                }
+            }
+        } else {
+            quote! {
+                #default_action
+                #result
+            }
+        };
+
+        let execute = wrap_return(quote! {
+            if let Some(action) = &mut ex.action {
+                #exec_args
+            } else {
+                #default
+            }
+        });
+        let fallback = wrap_return(quote! {
+            {
+                #release_lock
+                #default_action
+                #result
+            }
+        });
+        let on_call = find_on_call.as_ref().map(|find_on_call| {
+            let execute = wrap_return(exec_args.clone());
+
+            quote! {
+                #find_on_call
+
+                if let Some(on_call) = &on_call {
+                    if let Some(record) = call_index.and_then(|i| locked.calls.get_mut(i)) {
+                        record.expectation = Some(on_call.to_string());
+                    }
+                }
+
+                if let Some(action) = on_call.and_then(|on_call| on_call.action.as_mut()) {
+                    #execute
+                }
+            }
+        });
+
+        let fallback = if *no_default_impl {
+            let msg = format!("No default implementation for {call}");
+
+            quote!(panic!(#msg))
+        } else {
+            fallback
+        };
+
+        /* associated functions are not bound to a mock object, so they are always strict */
+        let not_found = if *is_associated {
+            quote! {
+                println!("{msg}");
+
+                panic!(#error);
+            }
         } else {
-            format!("{}", impl_.self_ty.to_token_stream())
+            quote! {
+                #on_call
+
+                /* only uninteresting calls (calls of methods without any expectation) are
+                 * handled by the policy, unexpected calls will always raise a panic */
+                match locked.policy {
+                    _ if has_expectations => {
+                        println!("{msg}");
+
+                        panic!(#error);
+                    }
+                    #ident_murf :: Policy::Strict => {
+                        println!("{msg}");
+
+                        panic!(#error);
+                    }
+                    #ident_murf :: Policy::Naggy => println!(#uninteresting),
+                    #ident_murf :: Policy::Nice => (),
+                }
+
+                #fallback
+            }
         };
-        let error = format!(
-            "No suitable expectation found for {}::{}",
-            error, &method.sig.ident
-        );
 
-        method.block.stmts = vec![Stmt::Item(Item::Verbatim(quote! {
+        /* calls of methods that have expectations are never uninteresting */
+        let has_expectations_init =
+            (!is_associated).then(|| quote!(let mut has_expectations = false;));
+        let has_expectations_update = (!is_associated).then(|| {
+            quote! {
+                has_expectations = has_expectations || ex.type_signature() == type_name::<#type_signature>();
+            }
+        });
+
+        let body = quote! {
             #locked
+            #record_call
             let args = #arg_names;
 
             let mut msg = String::new();
             let _ = writeln!(msg, #error);
-            let _ = writeln!(msg, "Tried the following expectations:");
+            let _ = writeln!(msg, "Tried the following expectations ({match_order}):");
 
-            for ex in #expectations_iter {
+            #has_expectations_init
+            for ex in match_order.apply(#expectations_iter) {
                 #expectation_unwrap
+                #has_expectations_update
+
+                /* retired expectations are neither considered nor reported */
+                if ex.is_retired() {
+                    continue;
+                }
 
                 let _ = writeln!(msg, "- {ex}");
 
@@ -204,38 +573,9 @@ impl MockMethod {
                     is_valid = false;
                 }
 
-                /* is done? */
-                if ex.times.is_done() {
-                    let _ = writeln!(msg, "    Call count:          done");
-
+                /* is able to handle the call? */
+                if !#ident_murf :: misc::check_call_state(&mut msg, &ex.times, &ex.sequences, &ex.after) {
                     is_valid = false;
-                } else if ex.times.is_ready() {
-                    let _ = writeln!(msg, "    Call count:          ready");
-                } else {
-                    let _ = writeln!(msg, "    Call count:          ok");
-                }
-
-                /* is active? */
-                for seq_handle in &ex.sequences {
-                    if seq_handle.is_done() {
-                        is_valid = false;
-
-                        let s = seq_handle.sequence_id().to_string();
-                        let _ = writeln!(msg, "    Sequence #{}:{:>2$}done", s, "", 10 - s.len());
-                    } else if seq_handle.is_active() {
-                        let s = seq_handle.sequence_id().to_string();
-                        let _ = writeln!(msg, "    Sequence #{}:{:>2$}active", s, "", 10 - s.len());
-                    } else {
-                        is_valid = false;
-
-                        let s = seq_handle.sequence_id().to_string();
-                        let _ = writeln!(msg, "    Sequence #{}:{:>2$}not active", s, "", 10 - s.len());
-                        let _ = writeln!(msg, "        has unsatisfied expectations");
-
-                        for ex in seq_handle.unsatisfied() {
-                            let _ = writeln!(msg, "          - {ex}");
-                        }
-                    }
                 }
 
                 if !is_valid {
@@ -243,26 +583,135 @@ impl MockMethod {
                 }
 
                 /* execute */
+                ex.commit_match();
                 ex.times.increment();
+                ex.reference.update(&ex.times);
+                #record_match
                 if ex.times.is_ready() {
                     for seq_handle in &ex.sequences {
                         seq_handle.set_ready();
                     }
                 }
 
-                return if let Some(action) = &mut ex.action {
-                    action.exec(args)
-                } else {
-                    #default_action
-                    #result
-                };
+                #execute
             }
 
-            println!("{msg}");
+            #not_found
+        };
 
-            panic!(#error);
-        }))];
+        method.block.stmts = vec![Stmt::Item(Item::Verbatim(body))];
 
-        method.remove_murf_attrs()
+        (method.remove_murf_attrs(), original)
+    }
+
+    /// Expand the signature of an `async` method of an `#[async_trait]` impl
+    /// block the same way `#[async_trait]` does.
+    ///
+    /// `#[async_trait]` only transforms `async fn`s, so the mocked method is
+    /// passed as a normal function that returns the boxed future. This way the
+    /// expectations are evaluated when the method is called, and not when the
+    /// returned future is polled for the first time.
+    fn expand_async_trait_signature(sig: &mut Signature, ga_impl: &Generics) {
+        let mut elided = TempLifetimes::default();
+        let mut predicates = Vec::<WherePredicate>::new();
+
+        for arg in &mut sig.inputs {
+            match arg {
+                FnArg::Receiver(r) if r.colon_token.is_some() => {
+                    *r.ty = (*r.ty)
+                        .clone()
+                        .replace_default_lifetime(LifetimeReplaceMode::Temp(&mut elided));
+                }
+                FnArg::Receiver(r) => {
+                    if let Some((_, lt)) = &mut r.reference {
+                        if !matches!(lt, Some(lt) if lt.ident != "_") {
+                            *lt = Some(elided.generate());
+                        }
+                    }
+                }
+                FnArg::Typed(t) => {
+                    *t.ty = (*t.ty)
+                        .clone()
+                        .replace_default_lifetime(LifetimeReplaceMode::Temp(&mut elided));
+                }
+            }
+        }
+
+        for param in &mut sig.generics.params {
+            match param {
+                GenericParam::Type(t) => {
+                    let ident = &t.ident;
+                    let bounds = std::mem::take(&mut t.bounds);
+                    t.colon_token = None;
+
+                    predicates.push(parse_quote!(#ident: 'async_trait + #bounds));
+                }
+                GenericParam::Lifetime(lt) => {
+                    let ident = &lt.lifetime;
+                    let bounds = std::mem::take(&mut lt.bounds);
+                    lt.colon_token = None;
+
+                    predicates.push(parse_quote!(#ident: 'async_trait + #bounds));
+                }
+                GenericParam::Const(_) => (),
+            }
+        }
+
+        for lt in ga_impl.lifetimes() {
+            let lt = &lt.lifetime;
+            let used = sig.inputs.iter().any(|arg| match arg {
+                FnArg::Receiver(r) => r.ty.contains_lifetime(lt),
+                FnArg::Typed(t) => t.ty.contains_lifetime(lt),
+            });
+
+            if used {
+                predicates.push(parse_quote!(#lt: 'async_trait));
+            }
+        }
+
+        for lt in elided.iter() {
+            sig.generics.params.push(parse_quote!(#lt));
+            predicates.push(parse_quote!(#lt: 'async_trait));
+        }
+
+        sig.generics.params.push(parse_quote!('async_trait));
+        if sig.receiver().is_some() {
+            predicates.push(parse_quote!(Self: 'async_trait));
+        }
+
+        sig.generics.lt_token.get_or_insert_with(Default::default);
+        sig.generics.gt_token.get_or_insert_with(Default::default);
+        sig.generics
+            .make_where_clause()
+            .predicates
+            .extend(predicates);
+    }
+
+    /// Get the result of the default action of an `async` method. `ret` is
+    /// the future returned by the default implementation of the state.
+    fn render_async_result(ret: &ReturnType, call: &str) -> TokenStream {
+        match ret {
+            ReturnType::Type(_, t) if t.to_formatted_string() == "Self" => quote! {{
+                let shared = this.shared.clone();
+                let handle = this.handle.clone();
+
+                Box::pin(async move {
+                    Self {
+                        state: ret.await,
+                        shared,
+                        handle,
+                    }
+                })
+            }},
+            ReturnType::Type(_, t) if t.contains_self_type() => {
+                let s = format!(
+                    "No default implementation for `{}` for {call}",
+                    t.to_formatted_string()
+                );
+
+                quote!(panic!(#s))
+            }
+            _ => quote!(Box::pin(ret)),
+        }
     }
 }
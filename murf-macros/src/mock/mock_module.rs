@@ -91,12 +91,13 @@ impl MockModule {
     ) -> ImplItemFn {
         let context = MethodContext::new(context, impl_, method);
 
-        let ret = MockMethod::render(&context, method.clone());
+        let (ret, original) = MockMethod::render(&context, method.clone());
 
         self.handle.add_method(context.clone());
         self.shared.add_expectation(context.clone());
-        self.expectations
-            .push(ExpectationModule::new(context, parsed, impl_, method));
+        self.expectations.push(ExpectationModule::new(
+            context, parsed, impl_, method, original,
+        ));
 
         ret
     }
@@ -1,10 +1,12 @@
 #![allow(clippy::module_inception)]
+mod automock;
 mod context;
 mod expectation;
 mod expectation_builder;
 mod expectation_module;
 mod handle;
 mod mock;
+mod mock_fn;
 mod mock_method;
 mod mock_module;
 mod mockable;
@@ -14,9 +16,11 @@ mod parsed;
 mod shared;
 
 use proc_macro2::TokenStream;
-use quote::ToTokens;
-use syn::parse2;
+use quote::{quote, ToTokens};
+use syn::{parse2, ItemTrait};
 
+use automock::{Automock, AutomockArgs};
+use mock_fn::{MockFn, MockFns};
 use mocked::Mocked;
 use parsed::Parsed;
 
@@ -41,6 +45,67 @@ pub(crate) fn exec(input: TokenStream) -> TokenStream {
     tokens
 }
 
+pub(crate) fn exec_automock(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match parse2::<AutomockArgs>(attr) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let trait_ = match parse2::<ItemTrait>(item) {
+        Ok(trait_) => trait_,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    #[allow(clippy::let_and_return)]
+    let tokens = Automock::new(args, trait_).into_token_stream();
+
+    #[cfg(feature = "debug")]
+    println!("\nautomock:\n{tokens:#}\n");
+
+    tokens
+}
+
+pub(crate) fn exec_mock_fn(input: TokenStream) -> TokenStream {
+    let fns = match parse2::<MockFns>(input) {
+        Ok(fns) => fns,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    #[allow(clippy::let_and_return)]
+    let tokens = fns.into_token_stream();
+
+    #[cfg(feature = "debug")]
+    println!("\nmock_fn!:\n{tokens:#}\n");
+
+    tokens
+}
+
+pub(crate) fn exec_mockable_fn(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new_spanned(attr, "`#[mockable_fn]` does not expect any arguments!")
+            .to_compile_error();
+    }
+
+    let original = item.clone();
+    let fn_ = match parse2::<MockFn>(item) {
+        Ok(fn_) => fn_.cfg_test(),
+        Err(err) => return err.to_compile_error(),
+    };
+
+    #[allow(clippy::let_and_return)]
+    let tokens = quote! {
+        #[cfg(not(test))]
+        #original
+
+        #fn_
+    };
+
+    #[cfg(feature = "debug")]
+    println!("\nmockable_fn:\n{tokens:#}\n");
+
+    tokens
+}
+
 #[cfg(feature = "debug-to-file")]
 fn debug_to_file(tokens: &TokenStream, ident: &str) -> std::io::Result<()> {
     use std::fs::{create_dir_all, write};
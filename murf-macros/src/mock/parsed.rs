@@ -1,16 +1,24 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use syn::{
-    parse::{discouraged::Speculative, Parse, ParseStream, Parser, Result as ParseResult},
+    braced, parenthesized,
+    parse::{
+        discouraged::Speculative, Error as ParseError, Parse, ParseStream, Parser,
+        Result as ParseResult,
+    },
     parse2,
     punctuated::Punctuated,
-    token::{Brace, Comma},
-    Attribute, Block, Generics, ImplItem, ImplItemFn, Item, ItemEnum, ItemImpl, ItemStruct, Meta,
-    Path, ReturnType, Stmt, TraitItemFn, Type, Visibility,
+    token::{Brace, Comma, Paren},
+    Attribute, Block, FnArg, Generics, ImplItem, ImplItemFn, Item, ItemEnum, ItemImpl, ItemStruct,
+    Meta, Path, ReturnType, Signature, Stmt, TraitItemFn, Type, Visibility,
 };
 
 use crate::misc::AttribsEx;
 
+use super::context::{
+    check_impl_trait_arg, check_impl_trait_return, erased_type, impl_trait_bounds,
+};
+
 /// Parsed code inside the mock! macro
 pub(crate) struct Parsed {
     pub ty: TypeToMock,
@@ -21,20 +29,46 @@ pub(crate) struct Parsed {
 }
 
 impl Parsed {
-    fn add_default_impl(impl_: &mut ItemImpl) -> ParseResult<()> {
+    fn add_default_impl(impl_: &mut ItemImpl, derive_send: bool) -> ParseResult<()> {
         for i in &mut impl_.items {
+            if let ImplItem::Fn(f) = i {
+                Self::check_impl_trait_args(&f.sig)?;
+
+                if let ReturnType::Type(_, t) = &f.sig.output {
+                    check_impl_trait_return(t)?;
+                }
+            }
+
             if let ImplItem::Verbatim(ts) = i {
-                let TraitItemFn { mut attrs, sig, .. } = parse2::<TraitItemFn>(ts.clone())?;
+                let (vis, TraitItemFn { mut attrs, sig, .. }) =
+                    Parser::parse2(parse_fn_signature, ts.clone())?;
+
+                Self::check_impl_trait_args(&sig)?;
 
                 let mut block = Block {
                     brace_token: Brace::default(),
                     stmts: Vec::new(),
                 };
 
-                if sig.output != ReturnType::Default {
-                    block.stmts = vec![Stmt::Item(Item::Verbatim(quote!(
-                        panic!("No default action specified!");
-                    )))];
+                if let ReturnType::Type(_, t) = &sig.output {
+                    check_impl_trait_return(t)?;
+
+                    block.stmts = if let Some(bounds) = impl_trait_bounds(t) {
+                        let trait_send = derive_send.then(|| quote!( + Send));
+                        let ty = erased_type(&bounds, trait_send.as_ref(), &TokenStream::new());
+
+                        vec![Stmt::Item(Item::Verbatim(quote! {
+                            #[allow(unreachable_code, clippy::diverging_sub_expression)]
+                            let ret: #ty = panic!("No default action specified!");
+
+                            #[allow(unreachable_code)]
+                            ret
+                        }))]
+                    } else {
+                        vec![Stmt::Item(Item::Verbatim(quote!(
+                            panic!("No default action specified!");
+                        )))]
+                    };
                 }
 
                 let attr = quote!(#[allow(unused_variables)]);
@@ -42,7 +76,7 @@ impl Parsed {
 
                 *i = ImplItem::Fn(ImplItemFn {
                     attrs,
-                    vis: Visibility::Inherited,
+                    vis,
                     defaultness: None,
                     sig,
                     block,
@@ -53,6 +87,57 @@ impl Parsed {
         Ok(())
     }
 
+    fn check_impl_trait_args(sig: &Signature) -> ParseResult<()> {
+        for arg in &sig.inputs {
+            if let FnArg::Typed(t) = arg {
+                check_impl_trait_arg(&t.ty)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generate the implementations of the supertraits that are defined using the
+    /// `#[murf(supertraits(...))]` attribute of the passed `impl_` block.
+    ///
+    /// `Send` and `Sync` are skipped, because they are handled by the derives of
+    /// the mocked type.
+    ///
+    /// This is only a shorthand: supertraits may also be implemented using a
+    /// separate `impl Supertrait for Type` block inside the same `mock!` block.
+    fn split_off_supertraits(impl_: &ItemImpl) -> ParseResult<Vec<ItemImpl>> {
+        let mut ret = Vec::new();
+
+        let (ga_impl, _ga_types, ga_where) = impl_.generics.split_for_impl();
+        let self_ty = &impl_.self_ty;
+
+        for attr in &impl_.attrs {
+            let Meta::List(ml) = &attr.meta else {
+                continue;
+            };
+
+            if !attr.path().is_ident("murf") {
+                continue;
+            }
+
+            for supertrait in ml.parse_args_with(parse_supertraits)? {
+                let Supertrait { path, items } = supertrait;
+
+                if path.is_ident("Send") || path.is_ident("Sync") {
+                    continue;
+                }
+
+                ret.push(parse2::<ItemImpl>(quote! {
+                    impl #ga_impl #path for #self_ty #ga_where {
+                        #( #items )*
+                    }
+                })?);
+            }
+        }
+
+        Ok(ret)
+    }
+
     fn remove_uneeded_derives(ty: &mut TypeToMock) -> ParseResult<()> {
         let attrs = match ty {
             TypeToMock::Enum(o) => Some(&mut o.attrs),
@@ -117,7 +202,13 @@ impl Parse for Parsed {
                 return Err(input.error("Implementing mock traits for different type in the same mock!{} block is not supported!"));
             }
 
-            Self::add_default_impl(&mut impl_)?;
+            for mut supertrait_impl in Self::split_off_supertraits(&impl_)? {
+                Self::add_default_impl(&mut supertrait_impl, derive_send)?;
+
+                impls.push(supertrait_impl);
+            }
+
+            Self::add_default_impl(&mut impl_, derive_send)?;
 
             impls.push(impl_);
         }
@@ -143,6 +234,82 @@ impl ToTokens for Parsed {
     }
 }
 
+/// Supertrait of a mocked trait that was defined using `#[murf(supertraits(...))]`.
+///
+/// The methods of the supertrait are defined as a list of function signatures
+/// inside braces (like `Sensor { fn read(&self) -> u32; }`). Supertraits without
+/// any required method can be defined using the plain path.
+struct Supertrait {
+    path: Path,
+    items: Vec<TraitItemFn>,
+}
+
+impl Parse for Supertrait {
+    fn parse(input: ParseStream<'_>) -> ParseResult<Self> {
+        let path = input.parse::<Path>()?;
+
+        let mut items = Vec::new();
+        if input.peek(Brace) {
+            let content;
+            braced!(content in input);
+
+            while !content.is_empty() {
+                items.push(content.parse::<TraitItemFn>()?);
+            }
+        }
+
+        Ok(Self { path, items })
+    }
+}
+
+/// Parse a function signature without body that may have a visibility (like
+/// `pub fn fuu(&self);` inside a inherent impl block).
+fn parse_fn_signature(input: ParseStream<'_>) -> ParseResult<(Visibility, TraitItemFn)> {
+    let attrs = input.call(Attribute::parse_outer)?;
+    let vis = input.parse::<Visibility>()?;
+    let mut item = input.parse::<TraitItemFn>()?;
+
+    item.attrs = attrs;
+
+    Ok((vis, item))
+}
+
+/// Parse the supertraits of the arguments of a `#[murf(...)]` attribute.
+fn parse_supertraits(input: ParseStream<'_>) -> ParseResult<Vec<Supertrait>> {
+    let mut ret = Vec::new();
+
+    while !input.is_empty() {
+        let ident = input.parse::<Ident>()?;
+
+        if ident != "supertraits" {
+            return Err(ParseError::new(
+                ident.span(),
+                format!(
+                    "Unknown argument `{ident}` for `#[murf(...)]`, expected `supertraits(...)`!"
+                ),
+            ));
+        }
+
+        if !input.peek(Paren) {
+            return Err(ParseError::new(
+                ident.span(),
+                "Expected list of supertraits: `supertraits(...)`!",
+            ));
+        }
+
+        let content;
+        parenthesized!(content in input);
+
+        ret.extend(Punctuated::<Supertrait, Comma>::parse_terminated(&content)?);
+
+        if !input.is_empty() {
+            input.parse::<Comma>()?;
+        }
+    }
+
+    Ok(ret)
+}
+
 /// Object the mock is implemented for
 pub(crate) enum TypeToMock {
     Enum(ItemEnum),
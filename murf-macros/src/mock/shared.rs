@@ -24,6 +24,7 @@ impl Shared {
 }
 
 impl ToTokens for Shared {
+    #[allow(clippy::too_many_lines)]
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Self {
             context,
@@ -44,6 +45,7 @@ impl ToTokens for Shared {
 
         let expectation_field_defs = expectations.iter().map(|cx| {
             let field = &cx.ident_expectation_field;
+            let on_calls = &cx.ident_on_calls_field;
 
             if cx.is_associated {
                 quote! {
@@ -51,20 +53,29 @@ impl ToTokens for Shared {
                 }
             } else {
                 quote! {
-                    #field: Vec<Box<dyn #ident_murf :: Expectation #trait_send #trait_sync + 'mock>>
+                    #field: Vec<Box<dyn #ident_murf :: Expectation #trait_send #trait_sync + 'mock>>,
+                    #on_calls: Vec<Box<dyn #ident_murf :: Expectation #trait_send #trait_sync + 'mock>>
                 }
             }
         });
 
         let expectation_field_ctor = expectations.iter().map(|cx| {
             let field = &cx.ident_expectation_field;
+            let on_calls = &cx.ident_on_calls_field;
 
-            quote! {
-                #field: Vec::new()
+            if cx.is_associated {
+                quote! {
+                    #field: Vec::new()
+                }
+            } else {
+                quote! {
+                    #field: Vec::new(),
+                    #on_calls: Vec::new()
+                }
             }
         });
 
-        let expectation_err = format!("Mocked object '{ident_state}' has unfulfilled expectations");
+        let object = format!("Mocked object '{ident_state}'");
 
         let expectations = expectations.iter().map(|cx| {
             let MethodContextData {
@@ -89,16 +100,10 @@ impl ToTokens for Shared {
             quote! {
                 for ex in &self.#ident_expectation_field {
                     #expectation_unwrap
-                    if ex.is_ready() {
+                    if ex.is_ready() || ex.is_retired() {
                         ex.set_done();
                     } else {
-                        if !raise {
-                            println!();
-                            println!(#expectation_err);
-                            raise = true;
-                        }
-
-                        println!("- {}", &ex);
+                        report.push(#ident_murf :: verification::UnfulfilledExpectation::from_expectation(&**ex));
                     }
                 }
 
@@ -112,19 +117,20 @@ impl ToTokens for Shared {
             /// State that is shared between the different helper objects.
             pub struct Shared #ga_mock_types #ga_mock_where {
                 #( #expectation_field_defs, )*
+                policy: #ident_murf :: Policy,
+                match_order: #ident_murf :: MatchOrder,
+                calls: #ident_murf :: CallHistory,
+                faults: #ident_murf :: FaultLog,
                 _marker: #ga_mock_phantom,
             }
 
             impl #ga_mock_impl Shared #ga_mock_types #ga_mock_where {
-                pub(super) fn checkpoint(&mut self) {
-                    let mut raise = false;
+                pub(super) fn checkpoint(&mut self) -> Result<(), #ident_murf :: VerificationReport> {
+                    let mut report = #ident_murf :: VerificationReport::new(#object);
 
                     #( #expectations )*
 
-                    if raise {
-                        println!();
-                        panic!(#expectation_err);
-                    }
+                    report.into_result()
                 }
             }
 
@@ -132,6 +138,10 @@ impl ToTokens for Shared {
                 fn default() -> Self {
                     Self {
                         #( #expectation_field_ctor, )*
+                        policy: #ident_murf :: Policy::default(),
+                        match_order: #ident_murf :: MatchOrder::default(),
+                        calls: #ident_murf :: CallHistory::new(),
+                        faults: #ident_murf :: FaultLog::default(),
                         _marker: PhantomData,
                     }
                 }
@@ -0,0 +1,196 @@
+use super::{Action, SideEffect};
+
+/// Trait to access the `N`th argument of the arguments passed to an [`Action`](super::Action).
+///
+/// This is implemented for tuples of one to ten arguments.
+///
+/// The index `N` refers to the arguments as they are passed to the action,
+/// which includes the `self` argument: for methods `self` is the argument with
+/// index `0` and the first real argument has index `1`. For associated and free
+/// functions the first argument has index `0`. The index can not be shifted
+/// automatically, because the type of the arguments does not tell if the first
+/// element is `self` or not.
+pub trait NthArg<const N: usize> {
+    /// Type of the `N`th argument.
+    type Output;
+
+    /// Get a reference to the `N`th argument.
+    fn nth_arg(&self) -> &Self::Output;
+
+    /// Get a mutable reference to the `N`th argument.
+    fn nth_arg_mut(&mut self) -> &mut Self::Output;
+
+    /// Get the `N`th argument.
+    fn into_nth_arg(self) -> Self::Output;
+}
+
+macro_rules! impl_nth_arg {
+    ($types:tt => $( $index:tt: $output:ident ),+) => {
+        $(
+            impl_nth_arg!(@impl $types $index $output);
+        )+
+    };
+    (@impl [ $( $type:ident ),+ ] $index:tt $output:ident) => {
+        impl<$( $type ),+> NthArg<$index> for ($( $type, )+) {
+            type Output = $output;
+
+            fn nth_arg(&self) -> &Self::Output {
+                &self.$index
+            }
+
+            fn nth_arg_mut(&mut self) -> &mut Self::Output {
+                &mut self.$index
+            }
+
+            fn into_nth_arg(self) -> Self::Output {
+                self.$index
+            }
+        }
+    };
+}
+
+impl_nth_arg!([T0] => 0: T0);
+impl_nth_arg!([T0, T1] => 0: T0, 1: T1);
+impl_nth_arg!([T0, T1, T2] => 0: T0, 1: T1, 2: T2);
+impl_nth_arg!([T0, T1, T2, T3] => 0: T0, 1: T1, 2: T2, 3: T3);
+impl_nth_arg!([T0, T1, T2, T3, T4] => 0: T0, 1: T1, 2: T2, 3: T3, 4: T4);
+impl_nth_arg!([T0, T1, T2, T3, T4, T5] => 0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5);
+impl_nth_arg!([T0, T1, T2, T3, T4, T5, T6] => 0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6);
+impl_nth_arg!([T0, T1, T2, T3, T4, T5, T6, T7] => 0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7);
+impl_nth_arg!([T0, T1, T2, T3, T4, T5, T6, T7, T8] => 0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8);
+impl_nth_arg!([T0, T1, T2, T3, T4, T5, T6, T7, T8, T9] => 0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7, 8: T8, 9: T9);
+
+/// Trait for callable arguments (like closures, function pointers or boxed
+/// functions) that can be invoked with a tuple of values.
+///
+/// This is implemented for any [`FnOnce`] that takes up to ten arguments.
+pub trait InvokeWith<Args> {
+    /// Result of the call.
+    type Output;
+
+    /// Call the callable with the passed `args`.
+    fn invoke_with(self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_invoke_with {
+    ($( $arg:ident ),*) => {
+        impl<F, R, $( $arg ),*> InvokeWith<($( $arg, )*)> for F
+        where
+            F: FnOnce($( $arg ),*) -> R,
+        {
+            type Output = R;
+
+            #[allow(non_snake_case)]
+            fn invoke_with(self, args: ($( $arg, )*)) -> R {
+                let ($( $arg, )*) = args;
+
+                self($( $arg ),*)
+            }
+        }
+    };
+}
+
+impl_invoke_with!();
+impl_invoke_with!(A0);
+impl_invoke_with!(A0, A1);
+impl_invoke_with!(A0, A1, A2);
+impl_invoke_with!(A0, A1, A2, A3);
+impl_invoke_with!(A0, A1, A2, A3, A4);
+impl_invoke_with!(A0, A1, A2, A3, A4, A5);
+impl_invoke_with!(A0, A1, A2, A3, A4, A5, A6);
+impl_invoke_with!(A0, A1, A2, A3, A4, A5, A6, A7);
+impl_invoke_with!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
+impl_invoke_with!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+
+/// Creates a [`ReturnArg`] action that returns the `N`th argument of the call.
+///
+/// The argument is converted into the return type using [`Into`].
+pub fn return_arg<const N: usize>() -> ReturnArg<N> {
+    ReturnArg
+}
+
+/// Action that returns the `N`th argument of the call.
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct ReturnArg<const N: usize>;
+
+impl<const N: usize, X, R> Action<X, R> for ReturnArg<N>
+where
+    X: NthArg<N>,
+    X::Output: Into<R>,
+{
+    fn exec(self, args: X) -> R {
+        args.into_nth_arg().into()
+    }
+}
+
+/// Creates a [`InvokeArg`] action that calls the `N`th argument of the call
+/// with the passed `values`.
+///
+/// `values` is a tuple of the arguments that are passed to the callable. The
+/// result of the callable is returned by the action.
+pub fn invoke_arg<const N: usize, V>(values: V) -> InvokeArg<N, V> {
+    InvokeArg(values)
+}
+
+/// Action that calls the `N`th argument of the call with a tuple of values.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct InvokeArg<const N: usize, V>(pub V);
+
+impl<const N: usize, V, X, R> Action<X, R> for InvokeArg<N, V>
+where
+    X: NthArg<N>,
+    X::Output: InvokeWith<V, Output = R>,
+{
+    fn exec(self, args: X) -> R {
+        args.into_nth_arg().invoke_with(self.0)
+    }
+}
+
+impl<const N: usize, V, X> SideEffect<X> for InvokeArg<N, V>
+where
+    X: NthArg<N>,
+    for<'x> &'x mut X::Output: InvokeWith<V>,
+{
+    fn apply(self, args: &mut X) {
+        args.nth_arg_mut().invoke_with(self.0);
+    }
+}
+
+/// Creates a [`InvokeArgWith`] action that calls the `N`th argument of the call
+/// with the values produced by the passed function `f`.
+///
+/// This is useful if the values can not be cloned or should be created fresh
+/// for each call.
+pub fn invoke_arg_with<const N: usize, F>(f: F) -> InvokeArgWith<N, F> {
+    InvokeArgWith(f)
+}
+
+/// Action that calls the `N`th argument of the call with the values produced
+/// by the function `F`.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct InvokeArgWith<const N: usize, F>(pub F);
+
+impl<const N: usize, F, V, X, R> Action<X, R> for InvokeArgWith<N, F>
+where
+    F: FnOnce() -> V,
+    X: NthArg<N>,
+    X::Output: InvokeWith<V, Output = R>,
+{
+    fn exec(self, args: X) -> R {
+        args.into_nth_arg().invoke_with((self.0)())
+    }
+}
+
+impl<const N: usize, F, V, X> SideEffect<X> for InvokeArgWith<N, F>
+where
+    F: FnOnce() -> V,
+    X: NthArg<N>,
+    for<'x> &'x mut X::Output: InvokeWith<V>,
+{
+    fn apply(self, args: &mut X) {
+        args.nth_arg_mut().invoke_with((self.0)());
+    }
+}
@@ -0,0 +1,46 @@
+use super::{Action, SideEffect};
+
+/// Creates a [`DoAll`] action that executes the passed `actions` one after
+/// another.
+///
+/// `actions` is a tuple of up to ten actions. All actions except the last one
+/// have to implement [`SideEffect`], the result of the last action is returned.
+pub fn do_all<T>(actions: T) -> DoAll<T> {
+    DoAll(actions)
+}
+
+/// Action that executes a tuple of actions one after another and returns the
+/// result of the last one.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct DoAll<T>(pub T);
+
+macro_rules! impl_do_all {
+    ($( $side_effect:ident ),*) => {
+        impl<X, R, $( $side_effect, )* L> Action<X, R> for DoAll<($( $side_effect, )* L,)>
+        where
+            $( $side_effect: SideEffect<X>, )*
+            L: Action<X, R>,
+        {
+            #[allow(non_snake_case, unused_mut)]
+            fn exec(self, mut args: X) -> R {
+                let ($( $side_effect, )* last,) = self.0;
+
+                $( $side_effect.apply(&mut args); )*
+
+                last.exec(args)
+            }
+        }
+    };
+}
+
+impl_do_all!();
+impl_do_all!(A0);
+impl_do_all!(A0, A1);
+impl_do_all!(A0, A1, A2);
+impl_do_all!(A0, A1, A2, A3);
+impl_do_all!(A0, A1, A2, A3, A4);
+impl_do_all!(A0, A1, A2, A3, A4, A5);
+impl_do_all!(A0, A1, A2, A3, A4, A5, A6);
+impl_do_all!(A0, A1, A2, A3, A4, A5, A6, A7);
+impl_do_all!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
@@ -0,0 +1,163 @@
+use std::sync::{atomic::Ordering, Arc};
+
+use parking_lot::Mutex;
+
+use crate::{fault::record_fault, Times};
+
+use super::Action;
+
+/// Creates a [`InjectFault`] action that executes the `failure` action for the
+/// `n`th call (starting at 1) and the `success` action for any other call.
+pub fn fail_nth<S, F>(n: usize, success: S, failure: F) -> InjectFault<S, F> {
+    InjectFault::new(FaultPattern::Nth(n), success, failure)
+}
+
+/// Creates a [`InjectFault`] action that executes the `failure` action for
+/// every `k`th call and the `success` action for any other call.
+///
+/// # Panics
+///
+/// Panics if `k` is zero.
+pub fn fail_every<S, F>(k: usize, success: S, failure: F) -> InjectFault<S, F> {
+    assert!(k > 0, "`fail_every` needs a period of at least 1");
+
+    InjectFault::new(FaultPattern::Every(k), success, failure)
+}
+
+/// Creates a [`InjectFault`] action that executes the `failure` action with the
+/// passed `probability` (`0.0` to `1.0`) and the `success` action otherwise.
+///
+/// The random numbers are generated from the passed `seed`, so the same seed
+/// will always inject the faults into the same calls.
+///
+/// # Panics
+///
+/// Panics if `probability` is not in the range `0.0` to `1.0` (or is `NaN`).
+pub fn fail_with_probability<S, F>(
+    probability: f64,
+    seed: u64,
+    success: S,
+    failure: F,
+) -> InjectFault<S, F> {
+    assert!(
+        (0.0..=1.0).contains(&probability),
+        "`fail_with_probability` needs a probability between 0.0 and 1.0"
+    );
+
+    InjectFault::new(
+        FaultPattern::Probability {
+            probability,
+            state: seed,
+        },
+        success,
+        failure,
+    )
+}
+
+/// Action that executes a success action `S` or injects a fault by executing
+/// the failure action `F`.
+///
+/// Clones of this action share their state, so this is meant to be used with
+/// `will_repeatedly`. Each injected fault is recorded in the fault log of the
+/// mocked object (see the `faults` and `fault_count` methods of the handle).
+#[must_use]
+#[derive(Debug)]
+pub struct InjectFault<S, F> {
+    success: S,
+    failure: F,
+    state: Arc<Mutex<FaultState>>,
+}
+
+#[derive(Debug)]
+struct FaultState {
+    times: Times,
+    pattern: FaultPattern,
+}
+
+#[derive(Debug)]
+enum FaultPattern {
+    Nth(usize),
+    Every(usize),
+    Probability { probability: f64, state: u64 },
+}
+
+impl<S, F> InjectFault<S, F> {
+    fn new(pattern: FaultPattern, success: S, failure: F) -> Self {
+        Self {
+            success,
+            failure,
+            state: Arc::new(Mutex::new(FaultState {
+                times: Times::default(),
+                pattern,
+            })),
+        }
+    }
+
+    /// Get the number of times the action was executed so far.
+    #[must_use]
+    pub fn call_count(&self) -> usize {
+        self.state.lock().times.count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of the current call and `true` if a fault should be
+    /// injected for it.
+    #[allow(clippy::manual_is_multiple_of)]
+    fn next_call(&self) -> (usize, bool) {
+        let mut state = self.state.lock();
+        let call = state.times.increment() + 1;
+
+        let fail = match &mut state.pattern {
+            FaultPattern::Nth(n) => call == *n,
+            FaultPattern::Every(k) => call % *k == 0,
+            FaultPattern::Probability { probability, state } => next_random(state) < *probability,
+        };
+
+        (call, fail)
+    }
+}
+
+impl<S, F> Clone for InjectFault<S, F>
+where
+    S: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            success: self.success.clone(),
+            failure: self.failure.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<S, F, X, R> Action<X, R> for InjectFault<S, F>
+where
+    S: Action<X, R>,
+    F: Action<X, R>,
+{
+    fn exec(self, args: X) -> R {
+        let (call, fail) = self.next_call();
+
+        if fail {
+            record_fault(call);
+
+            self.failure.exec(args)
+        } else {
+            self.success.exec(args)
+        }
+    }
+}
+
+/// Get the next random number in the range `0.0..1.0` using the `SplitMix64`
+/// generator.
+#[allow(clippy::cast_precision_loss)]
+fn next_random(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
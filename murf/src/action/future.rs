@@ -0,0 +1,143 @@
+use std::future::{ready, Future};
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+use super::{BoxedAction, RepeatableAction};
+
+/// Helper type to implement [`RepeatableAction`] that returns a boxed future
+/// for any [`RepeatableAction`] that returns a plain value.
+///
+/// This is used for expectations of `async` methods. The inner action is
+/// executed when the mocked method is called, the returned future is ready
+/// immediately.
+#[derive(Debug)]
+pub struct ReadyAction<X>(X);
+
+impl<X> ReadyAction<X> {
+    /// Create a new [`ReadyAction`] instance.
+    pub fn new(inner: X) -> Self {
+        Self(inner)
+    }
+}
+
+impl<'a, X, T, R> RepeatableAction<T, Pin<Box<dyn Future<Output = R> + 'a>>> for ReadyAction<X>
+where
+    X: RepeatableAction<T, R>,
+    R: 'a,
+{
+    fn exec(&mut self, args: T) -> Pin<Box<dyn Future<Output = R> + 'a>> {
+        Box::pin(ready(self.0.exec(args)))
+    }
+
+    fn detach<'b>(
+        &mut self,
+    ) -> Option<Box<dyn BoxedAction<T, Pin<Box<dyn Future<Output = R> + 'a>>> + 'b>>
+    where
+        Self: 'b,
+        T: 'b,
+        Pin<Box<dyn Future<Output = R> + 'a>>: 'b,
+    {
+        let action = self.0.detach()?;
+        let action = move |args: T| -> Pin<Box<dyn Future<Output = R> + 'a>> {
+            Box::pin(ready(action.exec_boxed(args)))
+        };
+
+        Some(Box::new(action))
+    }
+}
+
+impl<'a, X, T, R> RepeatableAction<T, Pin<Box<dyn Future<Output = R> + Send + 'a>>>
+    for ReadyAction<X>
+where
+    X: RepeatableAction<T, R>,
+    R: Send + 'a,
+{
+    fn exec(&mut self, args: T) -> Pin<Box<dyn Future<Output = R> + Send + 'a>> {
+        Box::pin(ready(self.0.exec(args)))
+    }
+
+    fn detach<'b>(
+        &mut self,
+    ) -> Option<Box<dyn BoxedAction<T, Pin<Box<dyn Future<Output = R> + Send + 'a>>> + 'b>>
+    where
+        Self: 'b,
+        T: 'b,
+        Pin<Box<dyn Future<Output = R> + Send + 'a>>: 'b,
+    {
+        let action = self.0.detach()?;
+        let action = move |args: T| -> Pin<Box<dyn Future<Output = R> + Send + 'a>> {
+            Box::pin(ready(action.exec_boxed(args)))
+        };
+
+        Some(Box::new(action))
+    }
+}
+
+/// Helper type to implement [`RepeatableAction`] that returns a boxed future
+/// for any [`RepeatableAction`] that returns a future `F`.
+///
+/// This is used for expectations of `async` methods that should execute an
+/// asynchronous action.
+#[derive(Debug)]
+pub struct FutureAction<X, F>(X, PhantomData<fn() -> F>);
+
+impl<X, F> FutureAction<X, F> {
+    /// Create a new [`FutureAction`] instance.
+    pub fn new(inner: X) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+
+impl<'a, X, T, F> RepeatableAction<T, Pin<Box<dyn Future<Output = F::Output> + 'a>>>
+    for FutureAction<X, F>
+where
+    X: RepeatableAction<T, F>,
+    F: Future + 'a,
+{
+    fn exec(&mut self, args: T) -> Pin<Box<dyn Future<Output = F::Output> + 'a>> {
+        Box::pin(self.0.exec(args))
+    }
+
+    fn detach<'b>(
+        &mut self,
+    ) -> Option<Box<dyn BoxedAction<T, Pin<Box<dyn Future<Output = F::Output> + 'a>>> + 'b>>
+    where
+        Self: 'b,
+        T: 'b,
+        Pin<Box<dyn Future<Output = F::Output> + 'a>>: 'b,
+    {
+        let action = self.0.detach()?;
+        let action = move |args: T| -> Pin<Box<dyn Future<Output = F::Output> + 'a>> {
+            Box::pin(action.exec_boxed(args))
+        };
+
+        Some(Box::new(action))
+    }
+}
+
+impl<'a, X, T, F> RepeatableAction<T, Pin<Box<dyn Future<Output = F::Output> + Send + 'a>>>
+    for FutureAction<X, F>
+where
+    X: RepeatableAction<T, F>,
+    F: Future + Send + 'a,
+{
+    fn exec(&mut self, args: T) -> Pin<Box<dyn Future<Output = F::Output> + Send + 'a>> {
+        Box::pin(self.0.exec(args))
+    }
+
+    fn detach<'b>(
+        &mut self,
+    ) -> Option<Box<dyn BoxedAction<T, Pin<Box<dyn Future<Output = F::Output> + Send + 'a>>> + 'b>>
+    where
+        Self: 'b,
+        T: 'b,
+        Pin<Box<dyn Future<Output = F::Output> + Send + 'a>>: 'b,
+    {
+        let action = self.0.detach()?;
+        let action = move |args: T| -> Pin<Box<dyn Future<Output = F::Output> + Send + 'a>> {
+            Box::pin(action.exec_boxed(args))
+        };
+
+        Some(Box::new(action))
+    }
+}
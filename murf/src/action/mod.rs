@@ -1,11 +1,50 @@
 //! The [`action`](self) module contains difference pre-defined actions that may
 //! be executed for a call-expectation of a mocked type.
+//!
+//! Actions that refer to an argument by its position (like `return_arg::<N>`
+//! or `save_arg::<N>`) count the `self` argument of methods as argument `0`,
+//! see [`NthArg`] for details.
 
+mod arg;
+mod do_all;
+mod fault;
+mod future;
 mod invoke;
+mod original;
+mod panic;
+mod result;
 mod returns;
+mod save;
+mod sequence;
+mod set;
+mod sync;
+mod then;
 
+pub use arg::{
+    invoke_arg, invoke_arg_with, return_arg, InvokeArg, InvokeArgWith, InvokeWith, NthArg,
+    ReturnArg,
+};
+pub use do_all::{do_all, DoAll};
+pub use fault::{fail_every, fail_nth, fail_with_probability, InjectFault};
+pub use future::{FutureAction, ReadyAction};
 pub use invoke::{invoke, Invoke};
-pub use returns::{return_, return_pointee, return_ref, Return, ReturnPointee, ReturnRef};
+pub use original::{call_original, CallOriginal, OriginalCall};
+pub use panic::{panic_with, PanicWith};
+pub use result::{
+    return_err, return_err_with, return_ok, return_ok_with, ReturnErr, ReturnErrWith, ReturnOk,
+    ReturnOkWith,
+};
+pub use returns::{
+    return_, return_default, return_pointee, return_ref, return_with, Return, ReturnDefault,
+    ReturnPointee, ReturnRef, ReturnWith,
+};
+pub use save::{save_arg, save_args, SaveArg, SaveArgs};
+pub use sequence::{cycle, return_iter, seq_actions, ReturnIter, SeqActions};
+pub use set::{set_arg, set_arg_from_slice, SetArg, SetArgFromSlice};
+pub use sync::{
+    signal_latch, sleep, wait_barrier, wait_latch, SignalLatch, Sleep, WaitBarrier, WaitLatch,
+};
+pub use then::{ActionExt, Then};
 
 /// Trait that defines an action that can only be executed once.
 ///
@@ -13,8 +52,7 @@ pub use returns::{return_, return_pointee, return_ref, Return, ReturnPointee, Re
 ///
 /// The arguments passed to the action are either
 /// - a unit `()` for no arguments
-/// - a single type `T` for one argument
-/// - or a tuple `(T1, T2, ...)` of many arguments
+/// - or a tuple `(T1,)`, `(T1, T2, ...)` of one or more arguments
 pub trait Action<T, R> {
     /// Execute the action with the passed arguments.
     fn exec(self, args: T) -> R;
@@ -29,12 +67,65 @@ where
     }
 }
 
+/// Trait that defines an action that is executed only for its side effects
+/// (like writing to a `&mut` argument) before another action produces the
+/// result of the call.
+///
+/// This is used by [`do_all`] to chain multiple actions.
+pub trait SideEffect<T> {
+    /// Apply the side effect to the passed arguments.
+    fn apply(self, args: &mut T);
+}
+
+impl<X, T> SideEffect<T> for X
+where
+    X: FnOnce(&mut T),
+{
+    fn apply(self, args: &mut T) {
+        self(args);
+    }
+}
+
+/// Helper trait to execute an [`Action`] that is stored in a [`Box`].
+pub trait BoxedAction<T, R> {
+    /// Execute the boxed action with the passed arguments.
+    fn exec_boxed(self: Box<Self>, args: T) -> R;
+}
+
+impl<X, T, R> BoxedAction<T, R> for X
+where
+    X: Action<T, R>,
+{
+    fn exec_boxed(self: Box<Self>, args: T) -> R {
+        (*self).exec(args)
+    }
+}
+
 /// Like [`Action`] but this action may be called repeatedly.
 ///
 /// This is similar to [`FnMut`] of the standard library.
 pub trait RepeatableAction<T, R> {
     /// Execute the action with the passed arguments.
     fn exec(&mut self, args: T) -> R;
+
+    /// Get a single-use action that executes the next call of this action
+    /// without borrowing it.
+    ///
+    /// The mocked object uses this to release its internal lock while the action
+    /// is executed. Returns `None` if the action can only be executed in place
+    /// using [`exec`](Self::exec), in this case the lock is held while the
+    /// action is executed. This is the case for closures that are used as
+    /// [`RepeatableAction`] directly (actions passed to `will_once` or
+    /// `will_repeatedly` are always detached) and for the actions of associated
+    /// functions, which are always executed in place.
+    fn detach<'a>(&mut self) -> Option<Box<dyn BoxedAction<T, R> + 'a>>
+    where
+        Self: 'a,
+        T: 'a,
+        R: 'a,
+    {
+        None
+    }
 }
 
 impl<X, T, R> RepeatableAction<T, R> for X
@@ -68,6 +159,18 @@ where
             .expect("Action was already executed")
             .exec(args)
     }
+
+    fn detach<'a>(&mut self) -> Option<Box<dyn BoxedAction<T, R> + 'a>>
+    where
+        Self: 'a,
+        T: 'a,
+        R: 'a,
+    {
+        let action: Box<dyn BoxedAction<T, R> + 'a> =
+            Box::new(self.0.take().expect("Action was already executed"));
+
+        Some(action)
+    }
 }
 
 /// Helper type to implement [`RepeatableAction`] for any action that implements
@@ -89,4 +192,53 @@ where
     fn exec(&mut self, args: T) -> R {
         self.0.clone().exec(args)
     }
+
+    fn detach<'a>(&mut self) -> Option<Box<dyn BoxedAction<T, R> + 'a>>
+    where
+        Self: 'a,
+        T: 'a,
+        R: 'a,
+    {
+        Some(Box::new(self.0.clone()))
+    }
+}
+
+/// Helper type to implement [`RepeatableAction`] for any [`RepeatableAction`]
+/// whose result is converted using the passed mapping function.
+///
+/// This is used to convert the result of an action into the type that is
+/// actually stored by an expectation (like a boxed trait object for mocked
+/// methods that return `impl Trait`).
+#[derive(Debug)]
+pub struct MapAction<X, R, U> {
+    inner: X,
+    map: fn(R) -> U,
+}
+
+impl<X, R, U> MapAction<X, R, U> {
+    /// Create a new [`MapAction`] instance.
+    pub fn new(inner: X, map: fn(R) -> U) -> Self {
+        Self { inner, map }
+    }
+}
+
+impl<X, T, R, U> RepeatableAction<T, U> for MapAction<X, R, U>
+where
+    X: RepeatableAction<T, R>,
+{
+    fn exec(&mut self, args: T) -> U {
+        (self.map)(self.inner.exec(args))
+    }
+
+    fn detach<'a>(&mut self) -> Option<Box<dyn BoxedAction<T, U> + 'a>>
+    where
+        Self: 'a,
+        T: 'a,
+        U: 'a,
+    {
+        let inner = self.inner.detach()?;
+        let map = self.map;
+
+        Some(Box::new(move |args: T| map(inner.exec_boxed(args))))
+    }
 }
@@ -0,0 +1,74 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+
+use super::Action;
+
+/// Creates a [`CallOriginal`] action that forwards the call to the original
+/// implementation of the mocked method.
+///
+/// The original implementation is the one provided by the state of the mocked
+/// object (the same that is used if no action was specified). The action can
+/// be used directly in `will_once` or `will_repeatedly`, or from inside any
+/// other action (by executing it with the arguments of the action), to wrap the
+/// original behavior.
+///
+/// The mocked method is identified by the type of the arguments and the result
+/// of the call. If two methods of the same mocked object share the same
+/// signature, use the `will_call_original`, `will_repeatedly_call_original` or
+/// `will_by_default_call_original` methods of the expectation builders instead,
+/// which are generated for each method that supports this action.
+///
+/// Calling the original implementation is only supported for non-associated,
+/// non-`async` methods that have a default implementation, that do not take
+/// `impl Trait` arguments and do not return `Self`, `impl Trait` or references.
+/// Using the action for any other method results in a compile error.
+pub fn call_original<M>() -> CallOriginal<M> {
+    CallOriginal(PhantomData)
+}
+
+/// Action that forwards the call to the original implementation of the mocked
+/// method `M`.
+#[must_use]
+pub struct CallOriginal<M>(PhantomData<fn() -> M>);
+
+impl<M> Clone for CallOriginal<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for CallOriginal<M> {}
+
+impl<M> Default for CallOriginal<M> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<M> Debug for CallOriginal<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("CallOriginal").finish()
+    }
+}
+
+impl<M, X, R> Action<X, R> for CallOriginal<M>
+where
+    X: OriginalCall<M, R>,
+{
+    fn exec(self, args: X) -> R {
+        args.call_original()
+    }
+}
+
+/// Trait that is implemented by the mocked objects for the arguments of each
+/// method that supports [`call_original`]. `M` identifies the mocked method and
+/// `R` is the result of the call.
+#[diagnostic::on_unimplemented(
+    message = "the original implementation can not be called with `{Self}`",
+    note = "`call_original` is only supported for non-associated, non-`async` methods that have a default implementation, that do not take `impl Trait` arguments and do not return `Self`, `impl Trait` or references"
+)]
+pub trait OriginalCall<M, R> {
+    /// Call the original implementation of the mocked method with `self` as
+    /// arguments.
+    fn call_original(self) -> R;
+}
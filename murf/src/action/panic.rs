@@ -0,0 +1,25 @@
+use std::fmt::Display;
+
+use super::Action;
+
+/// Creates a [`PanicWith`] action that panics with the passed `msg` when called.
+pub fn panic_with<M>(msg: M) -> PanicWith<M> {
+    PanicWith(msg)
+}
+
+/// Action that panics with the message `M` when called.
+#[must_use]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PanicWith<M>(pub M);
+
+impl<M, T, X> Action<X, T> for PanicWith<M>
+where
+    M: Display,
+{
+    /// # Panics
+    ///
+    /// Always panics with the message of the action.
+    fn exec(self, _args: X) -> T {
+        panic!("{}", self.0)
+    }
+}
@@ -0,0 +1,81 @@
+use super::Action;
+
+/// Creates a [`ReturnOk`] action that returns `Ok(value)` when called.
+pub fn return_ok<T>(value: T) -> ReturnOk<T> {
+    ReturnOk(value)
+}
+
+/// Action that returns `Ok(T)` when called.
+#[must_use]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReturnOk<T>(pub T);
+
+impl<T, E, X> Action<X, Result<T, E>> for ReturnOk<T> {
+    fn exec(self, _args: X) -> Result<T, E> {
+        Ok(self.0)
+    }
+}
+
+/// Creates a [`ReturnErr`] action that returns `Err(error)` when called.
+pub fn return_err<E>(error: E) -> ReturnErr<E> {
+    ReturnErr(error)
+}
+
+/// Action that returns `Err(E)` when called.
+#[must_use]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReturnErr<E>(pub E);
+
+impl<T, E, X> Action<X, Result<T, E>> for ReturnErr<E> {
+    fn exec(self, _args: X) -> Result<T, E> {
+        Err(self.0)
+    }
+}
+
+/// Creates a [`ReturnOkWith`] action that returns `Ok` with the value created
+/// by the passed function `f` when called.
+///
+/// The value is created for each call, so this can be used with
+/// `will_repeatedly` for values that do not implement [`Clone`].
+pub fn return_ok_with<F>(f: F) -> ReturnOkWith<F> {
+    ReturnOkWith(f)
+}
+
+/// Action that returns `Ok` with the value created by the function `F` when
+/// called.
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct ReturnOkWith<F>(pub F);
+
+impl<F, T, E, X> Action<X, Result<T, E>> for ReturnOkWith<F>
+where
+    F: FnOnce() -> T,
+{
+    fn exec(self, _args: X) -> Result<T, E> {
+        Ok((self.0)())
+    }
+}
+
+/// Creates a [`ReturnErrWith`] action that returns `Err` with the error created
+/// by the passed function `f` when called.
+///
+/// The error is created for each call, so this can be used with
+/// `will_repeatedly` for errors that do not implement [`Clone`].
+pub fn return_err_with<F>(f: F) -> ReturnErrWith<F> {
+    ReturnErrWith(f)
+}
+
+/// Action that returns `Err` with the error created by the function `F` when
+/// called.
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct ReturnErrWith<F>(pub F);
+
+impl<F, T, E, X> Action<X, Result<T, E>> for ReturnErrWith<F>
+where
+    F: FnOnce() -> E,
+{
+    fn exec(self, _args: X) -> Result<T, E> {
+        Err((self.0)())
+    }
+}
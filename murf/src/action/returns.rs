@@ -51,3 +51,46 @@ where
         self.0.get()
     }
 }
+
+/// Creates a [`ReturnDefault`] action that returns the [`Default`] value of the
+/// return type when called.
+pub fn return_default() -> ReturnDefault {
+    ReturnDefault
+}
+
+/// Action that returns the [`Default`] value of the return type when called.
+#[must_use]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReturnDefault;
+
+impl<T, X> Action<X, T> for ReturnDefault
+where
+    T: Default,
+{
+    fn exec(self, _args: X) -> T {
+        T::default()
+    }
+}
+
+/// Creates a [`ReturnWith`] action that returns the value created by the passed
+/// function `f` when called.
+///
+/// In contrast to [`Return`] the value is created for each call, so this can be
+/// used with `will_repeatedly` for values that do not implement [`Clone`].
+pub fn return_with<F>(f: F) -> ReturnWith<F> {
+    ReturnWith(f)
+}
+
+/// Action that returns the value created by the function `F` when called.
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct ReturnWith<F>(pub F);
+
+impl<F, T, X> Action<X, T> for ReturnWith<F>
+where
+    F: FnOnce() -> T,
+{
+    fn exec(self, _args: X) -> T {
+        (self.0)()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{slot::OwnedArg, Slot};
+
+use super::{arg::NthArg, Action, SideEffect};
+
+/// Creates a [`SaveArg`] action that stores the `N`th argument of the call
+/// in the passed `slot`.
+pub fn save_arg<const N: usize, T>(slot: &Slot<T>) -> SaveArg<N, T> {
+    SaveArg(slot.clone())
+}
+
+/// Action that stores the `N`th argument of the call in a [`Slot`].
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct SaveArg<const N: usize, T>(pub Slot<T>);
+
+impl<const N: usize, T, X> SideEffect<X> for SaveArg<N, T>
+where
+    X: NthArg<N>,
+    X::Output: OwnedArg<T>,
+{
+    fn apply(self, args: &mut X) {
+        self.0.set(args.nth_arg().owned_arg());
+    }
+}
+
+impl<const N: usize, T, X> Action<X, ()> for SaveArg<N, T>
+where
+    X: NthArg<N>,
+    X::Output: OwnedArg<T>,
+{
+    fn exec(self, mut args: X) {
+        self.apply(&mut args);
+    }
+}
+
+/// Creates a [`SaveArgs`] action that stores all arguments of the call in the
+/// passed `slot`.
+///
+/// The arguments are stored as tuple, the same way they are passed to the action.
+pub fn save_args<T>(slot: &Slot<T>) -> SaveArgs<T> {
+    SaveArgs(slot.clone())
+}
+
+/// Action that stores all arguments of the call in a [`Slot`].
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct SaveArgs<T>(pub Slot<T>);
+
+impl<T, X> SideEffect<X> for SaveArgs<T>
+where
+    X: OwnedArg<T>,
+{
+    fn apply(self, args: &mut X) {
+        self.0.set(args.owned_arg());
+    }
+}
+
+impl<T, X> Action<X, ()> for SaveArgs<T>
+where
+    X: OwnedArg<T>,
+{
+    fn exec(self, mut args: X) {
+        self.apply(&mut args);
+    }
+}
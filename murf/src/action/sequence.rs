@@ -0,0 +1,120 @@
+use std::iter::Cycle;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use super::Action;
+
+/// Creates a [`SeqActions`] action that executes the next action of the passed
+/// `actions` each time it is called.
+///
+/// Clones of the returned action share their state, so this is meant to be used
+/// with `will_repeatedly`. The action will panic once all actions were executed.
+pub fn seq_actions<I>(actions: I) -> SeqActions<I::IntoIter>
+where
+    I: IntoIterator,
+{
+    SeqActions::new("seq_actions", actions.into_iter())
+}
+
+/// Creates a [`SeqActions`] action that executes the next action of the passed
+/// `actions` each time it is called, and starts again with the first action
+/// once all actions were executed.
+pub fn cycle<I>(actions: I) -> SeqActions<Cycle<I::IntoIter>>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    SeqActions::new("cycle", actions.into_iter().cycle())
+}
+
+/// Creates a [`ReturnIter`] action that returns the next value of the passed
+/// `values` each time it is called.
+///
+/// Clones of the returned action share their state, so this is meant to be used
+/// with `will_repeatedly`. The action will panic once all values were returned.
+pub fn return_iter<I>(values: I) -> ReturnIter<I::IntoIter>
+where
+    I: IntoIterator,
+{
+    ReturnIter(SeqActions::new("return_iter", values.into_iter()))
+}
+
+/// Action that executes the next action of an iterator each time it is called.
+#[must_use]
+#[derive(Debug)]
+pub struct SeqActions<I> {
+    name: &'static str,
+    state: Arc<Mutex<SeqState<I>>>,
+}
+
+#[derive(Debug)]
+struct SeqState<I> {
+    iter: I,
+    calls: usize,
+}
+
+impl<I> SeqActions<I>
+where
+    I: Iterator,
+{
+    fn new(name: &'static str, iter: I) -> Self {
+        Self {
+            name,
+            state: Arc::new(Mutex::new(SeqState { iter, calls: 0 })),
+        }
+    }
+
+    fn next(&self) -> I::Item {
+        let mut state = self.state.lock();
+        state.calls += 1;
+
+        let calls = state.calls;
+        let name = self.name;
+
+        state.iter.next().unwrap_or_else(|| {
+            panic!(
+                "Action `{name}` is exhausted: call {calls} exceeds the number of provided items"
+            )
+        })
+    }
+}
+
+impl<I> Clone for SeqActions<I> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<I, X, R> Action<X, R> for SeqActions<I>
+where
+    I: Iterator,
+    I::Item: Action<X, R>,
+{
+    fn exec(self, args: X) -> R {
+        self.next().exec(args)
+    }
+}
+
+/// Action that returns the next value of an iterator each time it is called.
+#[must_use]
+#[derive(Debug)]
+pub struct ReturnIter<I>(SeqActions<I>);
+
+impl<I> Clone for ReturnIter<I> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I, X> Action<X, I::Item> for ReturnIter<I>
+where
+    I: Iterator,
+{
+    fn exec(self, _args: X) -> I::Item {
+        self.0.next()
+    }
+}
@@ -0,0 +1,75 @@
+use super::{arg::NthArg, Action, SideEffect};
+
+/// Creates a [`SetArg`] action that writes the passed `value` to the `N`th
+/// argument of the call, which has to be a mutable reference.
+pub fn set_arg<const N: usize, T>(value: T) -> SetArg<N, T> {
+    SetArg(value)
+}
+
+/// Action that writes a value to the `N`th argument of the call.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct SetArg<const N: usize, T>(pub T);
+
+impl<'a, const N: usize, T, X> SideEffect<X> for SetArg<N, T>
+where
+    X: NthArg<N, Output = &'a mut T>,
+    T: 'a,
+{
+    fn apply(self, args: &mut X) {
+        **args.nth_arg_mut() = self.0;
+    }
+}
+
+impl<'a, const N: usize, T, X> Action<X, ()> for SetArg<N, T>
+where
+    X: NthArg<N, Output = &'a mut T>,
+    T: 'a,
+{
+    fn exec(self, mut args: X) {
+        self.apply(&mut args);
+    }
+}
+
+/// Creates a [`SetArgFromSlice`] action that copies the passed `values` to the
+/// beginning of the `N`th argument of the call, which has to be a mutable slice.
+pub fn set_arg_from_slice<const N: usize, T: Clone>(values: &[T]) -> SetArgFromSlice<N, T> {
+    SetArgFromSlice(values.to_vec())
+}
+
+/// Action that copies values to the beginning of the `N`th argument of the call.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct SetArgFromSlice<const N: usize, T>(pub Vec<T>);
+
+impl<'a, const N: usize, T, X> SideEffect<X> for SetArgFromSlice<N, T>
+where
+    X: NthArg<N, Output = &'a mut [T]>,
+    T: Clone + 'a,
+{
+    /// # Panics
+    ///
+    /// Panics if the argument is shorter than the values to copy.
+    fn apply(self, args: &mut X) {
+        let buf = args.nth_arg_mut();
+
+        assert!(
+            self.0.len() <= buf.len(),
+            "Unable to set argument {N}: slice has length {} but {} values should be copied",
+            buf.len(),
+            self.0.len()
+        );
+
+        buf[..self.0.len()].clone_from_slice(&self.0);
+    }
+}
+
+impl<'a, const N: usize, T, X> Action<X, ()> for SetArgFromSlice<N, T>
+where
+    X: NthArg<N, Output = &'a mut [T]>,
+    T: Clone + 'a,
+{
+    fn exec(self, mut args: X) {
+        self.apply(&mut args);
+    }
+}
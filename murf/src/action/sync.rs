@@ -0,0 +1,91 @@
+use std::thread::sleep as thread_sleep;
+
+use crate::{types::Duration, Barrier, Latch};
+
+use super::{Action, SideEffect};
+
+macro_rules! impl_side_effect_action {
+    ($type:ty) => {
+        impl<X> Action<X, ()> for $type {
+            fn exec(self, mut args: X) {
+                self.apply(&mut args);
+            }
+        }
+    };
+}
+
+/// Creates a [`WaitBarrier`] action that blocks until all threads have reached
+/// the passed `barrier`.
+pub fn wait_barrier(barrier: &Barrier) -> WaitBarrier {
+    WaitBarrier(barrier.clone())
+}
+
+/// Action that blocks until all threads have reached a [`Barrier`].
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct WaitBarrier(pub Barrier);
+
+impl<X> SideEffect<X> for WaitBarrier {
+    fn apply(self, _args: &mut X) {
+        self.0.wait();
+    }
+}
+
+impl_side_effect_action!(WaitBarrier);
+
+/// Creates a [`WaitLatch`] action that blocks until the passed `latch` is
+/// released.
+pub fn wait_latch(latch: &Latch) -> WaitLatch {
+    WaitLatch(latch.clone())
+}
+
+/// Action that blocks until a [`Latch`] is released.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct WaitLatch(pub Latch);
+
+impl<X> SideEffect<X> for WaitLatch {
+    fn apply(self, _args: &mut X) {
+        self.0.wait();
+    }
+}
+
+impl_side_effect_action!(WaitLatch);
+
+/// Creates a [`SignalLatch`] action that counts down the passed `latch` when
+/// called.
+pub fn signal_latch(latch: &Latch) -> SignalLatch {
+    SignalLatch(latch.clone())
+}
+
+/// Action that counts down a [`Latch`] when called.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct SignalLatch(pub Latch);
+
+impl<X> SideEffect<X> for SignalLatch {
+    fn apply(self, _args: &mut X) {
+        self.0.count_down();
+    }
+}
+
+impl_side_effect_action!(SignalLatch);
+
+/// Creates a [`Sleep`] action that blocks the current thread for the passed
+/// `duration` (either a [`std::time::Duration`] or a [`Duration`]).
+pub fn sleep<D: Into<Duration>>(duration: D) -> Sleep {
+    Sleep(duration.into())
+}
+
+/// Action that blocks the current thread for a [`Duration`].
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct Sleep(pub Duration);
+
+impl<X> SideEffect<X> for Sleep {
+    fn apply(self, _args: &mut X) {
+        thread_sleep(self.0.into());
+    }
+}
+
+impl_side_effect_action!(Sleep);
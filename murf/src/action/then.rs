@@ -0,0 +1,40 @@
+use super::{Action, SideEffect};
+
+/// Extension trait that adds combinators to any action.
+pub trait ActionExt: Sized {
+    /// Creates a [`Then`] action that applies `self` as [`SideEffect`] and
+    /// returns the result of the `next` action afterwards.
+    fn then<B>(self, next: B) -> Then<Self, B> {
+        Then(self, next)
+    }
+}
+
+impl<X> ActionExt for X {}
+
+/// Action that applies a [`SideEffect`] `A` and executes the action `B`
+/// afterwards.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct Then<A, B>(pub A, pub B);
+
+impl<A, B, X, R> Action<X, R> for Then<A, B>
+where
+    A: SideEffect<X>,
+    B: Action<X, R>,
+{
+    fn exec(self, mut args: X) -> R {
+        self.0.apply(&mut args);
+        self.1.exec(args)
+    }
+}
+
+impl<A, B, X> SideEffect<X> for Then<A, B>
+where
+    A: SideEffect<X>,
+    B: SideEffect<X>,
+{
+    fn apply(self, args: &mut X) {
+        self.0.apply(args);
+        self.1.apply(args);
+    }
+}
@@ -0,0 +1,535 @@
+//! The [`closure`](self) module implements mocked closures that can be passed
+//! to any code that expects a [`Fn`], [`FnMut`] or [`FnOnce`].
+
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::action::{OnetimeAction, RepeatableAction, RepeatedAction};
+use crate::misc::check_call_state;
+use crate::verification::UnfulfilledExpectation;
+use crate::{
+    Action, ExpectationRef, InSequence, Matcher, Sequence, SequenceHandle, Times, TimesRange,
+    VerificationReport,
+};
+
+/// Create a new mocked closure.
+///
+/// `Args` is a tuple of the argument types of the closure (like `()`, `(u32,)`
+/// or `(u32, String)`) and `R` is the return type of the closure. The returned
+/// closure implements [`Fn`] (and therefore [`FnMut`] and [`FnOnce`] as well),
+/// the returned [`ClosureHandle`] is used to define the expectations for it.
+///
+/// The matchers and actions of the expectations receive the arguments of the
+/// call as `Args`. Expectations without an action panic if they are called,
+/// so closures that return `()` need an action like `Return(())` as well.
+///
+/// Arguments that contain a reference are bound to a single lifetime, so the
+/// closure does not implement `for<'x> Fn(&'x T)`. Code that requires such a
+/// closure can be passed a wrapper like `move |x: &T| closure(x.clone())`
+/// around a closure with owned arguments.
+///
+/// # Example
+///
+/// ```
+/// use murf::{expect_call, matcher::eq, action::Return, closure::mock_closure};
+///
+/// let (handle, closure) = mock_closure::<(u32,), bool>();
+///
+/// expect_call!(handle, call(eq(4))).will_once(Return(true));
+///
+/// assert!(closure(4));
+/// ```
+pub fn mock_closure<'a, Args, R>() -> (ClosureHandle<Args, R>, Args::Closure)
+where
+    Args: ClosureArgs<'a, R>,
+{
+    let handle = ClosureHandle {
+        shared: Arc::new(Mutex::new(Shared {
+            expectations: Vec::new(),
+        })),
+        check_on_drop: true,
+    };
+    let closure = Args::closure(ClosureHandle {
+        shared: handle.shared.clone(),
+        check_on_drop: false,
+    });
+
+    (handle, closure)
+}
+
+/// Trait that is implemented for tuples of the argument types of a closure that
+/// can be mocked using [`mock_closure`].
+pub trait ClosureArgs<'a, R>: Sized {
+    /// Type of the mocked closure.
+    type Closure;
+
+    /// Create the mocked closure that forwards each call to the passed `handle`.
+    fn closure(handle: ClosureHandle<Self, R>) -> Self::Closure;
+}
+
+/// Handle that is used to define the expectations of a mocked closure.
+///
+/// If the handle is dropped, all expectations are verified automatically.
+#[must_use]
+pub struct ClosureHandle<T, R> {
+    shared: Arc<Mutex<Shared<T, R>>>,
+    check_on_drop: bool,
+}
+
+struct Shared<T, R> {
+    expectations: Vec<ClosureExpectation<T, R>>,
+}
+
+impl<T, R> ClosureHandle<T, R> {
+    /// Add a new expectation for a call of the mocked closure.
+    ///
+    /// # Returns
+    /// Returns a [`ClosureExpectationBuilder`] that can be used to specialize
+    /// the expectation further.
+    pub fn expect_call(&self) -> ClosureExpectationBuilder<'_, T, R> {
+        ClosureExpectationBuilder::new(self)
+    }
+
+    /// Returns a reference to itself.
+    ///
+    /// This is used to make the public API of the handle compatible to the
+    /// handles of mocked objects.
+    #[must_use]
+    pub fn mock_handle(&self) -> &Self {
+        self
+    }
+
+    /// Check if all expectations have been fulfilled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if at least one expectation was not fulfilled.
+    pub fn checkpoint(&self) {
+        if let Err(report) = self.try_checkpoint() {
+            println!();
+            println!("{report}");
+            panic!("{}", report.title());
+        }
+    }
+
+    /// Check if all expectations have been fulfilled without raising a panic.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerificationReport`] that contains all unfulfilled
+    /// expectations if at least one expectation was not fulfilled. If the
+    /// handle could not be locked (because it is already locked by the current
+    /// thread), the returned report is marked as `locked`.
+    pub fn try_checkpoint(&self) -> Result<(), VerificationReport> {
+        let Some(mut shared) = self.shared.try_lock() else {
+            return Err(VerificationReport::locked("Mocked closure"));
+        };
+
+        let mut report = VerificationReport::new("Mocked closure");
+
+        for ex in &shared.expectations {
+            if ex.times.is_ready() || ex.is_retired() {
+                for seq_handle in &ex.sequences {
+                    seq_handle.set_done();
+                }
+            } else {
+                report.push(UnfulfilledExpectation::new(
+                    ex.to_string(),
+                    &ex.times,
+                    &ex.sequences,
+                ));
+            }
+        }
+
+        shared.expectations.clear();
+
+        report.into_result()
+    }
+
+    /// Drop the handle without checking the expectations.
+    pub fn release(mut self) {
+        self.check_on_drop = false;
+
+        drop(self);
+    }
+
+    /// Execute a call of the mocked closure with the passed `args`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no suitable expectation was found for the call or if the
+    /// expectation has no action.
+    pub fn call(&self, args: T) -> R {
+        let mut guard = self.shared.lock();
+
+        let mut msg = String::new();
+        let _ = writeln!(msg, "No suitable expectation found for <closure>");
+        let _ = writeln!(msg, "Tried the following expectations:");
+
+        for ex in &mut guard.expectations {
+            /* retired expectations are neither considered nor reported */
+            if ex.is_retired() {
+                continue;
+            }
+
+            let _ = writeln!(msg, "- {ex}");
+
+            let mut is_valid = true;
+
+            /* value matches? */
+            if ex.matches(&args) {
+                let _ = writeln!(msg, "    Argument matcher:    ok");
+            } else {
+                let _ = writeln!(msg, "    Argument matcher:    not ok");
+
+                is_valid = false;
+            }
+
+            /* is able to handle the call? */
+            if !check_call_state(&mut msg, &ex.times, &ex.sequences, &ex.after) {
+                is_valid = false;
+            }
+
+            if !is_valid {
+                continue;
+            }
+
+            /* execute */
+            ex.commit_match();
+            ex.times.increment();
+            ex.reference.update(&ex.times);
+            if ex.times.is_ready() {
+                for seq_handle in &ex.sequences {
+                    seq_handle.set_ready();
+                }
+            }
+
+            let Some(action) = &mut ex.action else {
+                panic!("No action specified for expectation {ex}");
+            };
+
+            /* the lock is released while the action is executed (if possible) */
+            if let Some(action) = action.detach() {
+                drop(guard);
+
+                return action.exec_boxed(args);
+            }
+
+            return action.exec(args);
+        }
+
+        println!("{msg}");
+
+        panic!("No suitable expectation found for <closure>");
+    }
+}
+
+impl<T, R> Debug for ClosureHandle<T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ClosureHandle")
+            .field("shared", &self.shared)
+            .field("check_on_drop", &self.check_on_drop)
+            .finish()
+    }
+}
+
+impl<T, R> Drop for ClosureHandle<T, R> {
+    fn drop(&mut self) {
+        if self.check_on_drop && !::std::thread::panicking() {
+            self.checkpoint();
+        }
+    }
+}
+
+impl<T, R> Debug for Shared<T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Shared")
+            .field("expectations", &self.expectations)
+            .finish()
+    }
+}
+
+/// Defines the values of an expected call to a mocked closure.
+#[allow(clippy::type_complexity)]
+pub struct ClosureExpectation<T, R> {
+    /// Defines how often the call is expected to be executed.
+    pub times: Times,
+
+    /// Human readable description of the expectation.
+    pub description: Option<String>,
+
+    /// Action that is executed once the actual call to the mocked closure is made.
+    pub action: Option<Box<dyn RepeatableAction<T, R> + Send + Sync>>,
+
+    /// Matcher that is used to verify the arguments of the call.
+    pub matcher: Option<Box<dyn Matcher<T> + Send + Sync>>,
+
+    /// List of sequences the expectation must respect.
+    pub sequences: Vec<SequenceHandle>,
+
+    /// List of expectations that must be satisfied before this expectation is active.
+    pub after: Vec<ExpectationRef>,
+
+    /// Reference that is used to track the state of this expectation.
+    pub reference: ExpectationRef,
+
+    /// Whether the expectation is retired once the upper bound of [`times`](Self::times) is reached.
+    pub retires_on_saturation: bool,
+}
+
+impl<T, R> ClosureExpectation<T, R> {
+    /// Check if the arguments of a call matches the expectation.
+    ///
+    /// # Returns
+    /// Returns `true` if the arguments are valid, `false` otherwise.
+    pub fn matches(&self, args: &T) -> bool {
+        if let Some(m) = &self.matcher {
+            m.matches(args)
+        } else {
+            true
+        }
+    }
+
+    /// Notify the matcher that the expectation was selected to handle the call
+    /// whose arguments were passed to the last call of [`matches`](Self::matches).
+    pub fn commit_match(&self) {
+        if let Some(m) = &self.matcher {
+            m.commit();
+        }
+    }
+
+    /// Returns `true` if this expectation is retired, `false` otherwise.
+    pub fn is_retired(&self) -> bool {
+        self.reference.is_retired() || (self.retires_on_saturation && self.times.is_done())
+    }
+}
+
+impl<T, R> Default for ClosureExpectation<T, R> {
+    fn default() -> Self {
+        Self {
+            times: Times::default(),
+            description: None,
+            action: None,
+            matcher: None,
+            sequences: Vec::new(),
+            after: Vec::new(),
+            reference: ExpectationRef::new(),
+            retires_on_saturation: false,
+        }
+    }
+}
+
+impl<T, R> Debug for ClosureExpectation<T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ClosureExpectation")
+            .field("times", &self.times)
+            .field("description", &self.description)
+            .field("action", &self.action.is_some())
+            .field("matcher", &self.matcher.is_some())
+            .field("sequences", &self.sequences)
+            .field("after", &self.after)
+            .field("reference", &self.reference)
+            .field("retires_on_saturation", &self.retires_on_saturation)
+            .finish()
+    }
+}
+
+impl<T, R> Display for ClosureExpectation<T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "<closure>")?;
+
+        if let Some(m) = &self.matcher {
+            write!(f, "(")?;
+            m.fmt(f)?;
+            write!(f, ")")?;
+        } else {
+            write!(f, "(..)")?;
+        }
+
+        if let Some(d) = &self.description {
+            write!(f, " {d}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Helper type that is used to set the values of a [`ClosureExpectation`] object
+/// before it is added to the list of expected calls of a mocked closure.
+#[must_use]
+pub struct ClosureExpectationBuilder<'a, T, R> {
+    handle: &'a ClosureHandle<T, R>,
+    expectation: Option<ClosureExpectation<T, R>>,
+}
+
+impl<'a, T, R> ClosureExpectationBuilder<'a, T, R> {
+    fn new(handle: &'a ClosureHandle<T, R>) -> Self {
+        let mut expectation = ClosureExpectation {
+            sequences: InSequence::create_handle().into_iter().collect(),
+            ..ClosureExpectation::default()
+        };
+        expectation.times.range = (1..).into();
+
+        Self {
+            handle,
+            expectation: Some(expectation),
+        }
+    }
+
+    /// Add a description to the expectation.
+    pub fn description<S: Into<String>>(mut self, value: S) -> Self {
+        self.expectation().description = Some(value.into());
+
+        self
+    }
+
+    /// Add a [`Matcher`] to the expectation.
+    ///
+    /// Matchers can be used to verify that the arguments of the call matches the expectation.
+    pub fn with<M: Matcher<T> + Send + Sync + 'static>(mut self, matcher: M) -> Self {
+        self.expectation().matcher = Some(Box::new(matcher));
+
+        self
+    }
+
+    /// Set the sequence the expectation should be executed in.
+    pub fn in_sequence(mut self, sequence: &Sequence) -> Self {
+        self.expectation().sequences = vec![sequence.create_handle()];
+
+        self
+    }
+
+    /// Add a sequence the expectation should be executed in.
+    pub fn add_sequence(mut self, sequence: &Sequence) -> Self {
+        self.expectation().sequences.push(sequence.create_handle());
+
+        self
+    }
+
+    /// Remove the expectation from all sequences.
+    pub fn no_sequences(mut self) -> Self {
+        self.expectation().sequences.clear();
+
+        self
+    }
+
+    /// Specify the number of calls for this expectation.
+    pub fn times<X: Into<TimesRange>>(mut self, range: X) -> Self {
+        self.expectation().times.range = range.into();
+
+        self
+    }
+
+    /// Add an expectation that must be satisfied before this expectation is active.
+    ///
+    /// This can be called multiple times to define more than one prerequisite.
+    /// Prerequisites that are retired do no longer block this expectation.
+    pub fn after(mut self, expectation: &ExpectationRef) -> Self {
+        self.expectation().after.push(expectation.clone());
+
+        self
+    }
+
+    /// Retire the expectation once the upper bound of its call count is reached.
+    ///
+    /// A retired expectation is no longer considered if the mocked closure is
+    /// called, so expectations that were defined before this one become
+    /// reachable again.
+    pub fn retires_on_saturation(mut self) -> Self {
+        self.expectation().retires_on_saturation = true;
+
+        self
+    }
+
+    /// Specify an action that should be executed once the actual call to the mocked closure was made.
+    ///
+    /// This will set `.times(1)` before the action is added. I you want to use
+    /// repeatedly executed actions please have a look at [`will_repeatedly`](Self::will_repeatedly).
+    pub fn will_once<A>(self, action: A) -> ExpectationRef
+    where
+        A: Action<T, R> + Send + Sync + 'static,
+    {
+        let mut this = self.times(1);
+        this.expectation().action = Some(Box::new(OnetimeAction::new(action)));
+
+        this.expectation_ref()
+    }
+
+    /// Specify an action that should be executed each time a call to the mocked closure was made.
+    pub fn will_repeatedly<A>(mut self, action: A) -> ExpectationRef
+    where
+        A: Action<T, R> + Send + Sync + Clone + 'static,
+    {
+        self.expectation().action = Some(Box::new(RepeatedAction::new(action)));
+
+        self.expectation_ref()
+    }
+
+    /// Get a [`ExpectationRef`] that references the expectation of this builder.
+    pub fn expectation_ref(&mut self) -> ExpectationRef {
+        self.expectation().reference.clone()
+    }
+
+    fn expectation(&mut self) -> &mut ClosureExpectation<T, R> {
+        self.expectation.as_mut().unwrap()
+    }
+}
+
+impl<T, R> Debug for ClosureExpectationBuilder<'_, T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ClosureExpectationBuilder")
+            .field("handle", &self.handle)
+            .field("expectation", self.expectation.as_ref().unwrap())
+            .finish()
+    }
+}
+
+impl<T, R> Drop for ClosureExpectationBuilder<'_, T, R> {
+    fn drop(&mut self) {
+        if let Some(expectation) = self.expectation.take() {
+            let desc = expectation.to_string();
+            expectation.reference.set_description(desc.clone());
+            expectation.reference.update(&expectation.times);
+
+            for seq_handle in &expectation.sequences {
+                seq_handle.set_description(desc.clone());
+
+                if expectation.times.is_ready() {
+                    seq_handle.set_ready();
+                }
+            }
+
+            self.handle.shared.lock().expectations.push(expectation);
+        }
+    }
+}
+
+macro_rules! impl_closure_args {
+    ($( $ty:ident $arg:ident ),*) => {
+        impl<'a, R, $( $ty ),*> ClosureArgs<'a, R> for ($( $ty, )*)
+        where
+            R: 'a,
+            $( $ty: 'a, )*
+        {
+            type Closure = Box<dyn Fn($( $ty ),*) -> R + Send + Sync + 'a>;
+
+            fn closure(handle: ClosureHandle<Self, R>) -> Self::Closure {
+                Box::new(move |$( $arg ),*| handle.call(($( $arg, )*)))
+            }
+        }
+    };
+}
+
+impl_closure_args!();
+impl_closure_args!(A0 a0);
+impl_closure_args!(A0 a0, A1 a1);
+impl_closure_args!(A0 a0, A1 a1, A2 a2);
+impl_closure_args!(A0 a0, A1 a1, A2 a2, A3 a3);
+impl_closure_args!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4);
+impl_closure_args!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5);
+impl_closure_args!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6);
+impl_closure_args!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7);
+impl_closure_args!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8);
+impl_closure_args!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9);
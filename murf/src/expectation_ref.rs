@@ -0,0 +1,110 @@
+//! The [`expectation_ref`](self) module implements the [`ExpectationRef`] type.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::sync::{atomic::Ordering, Arc};
+
+use parking_lot::Mutex;
+
+use crate::Times;
+
+/// Lightweight reference to an expectation that was added to a mocked object.
+///
+/// The reference is returned by the `will_once` and `will_repeatedly` methods
+/// of the expectation builder. It can be passed to the `after` method of an
+/// other expectation to define that the other expectation is only active after
+/// this one is satisfied.
+///
+/// The reference can also be used to inspect the progress of the expectation
+/// (without calling `checkpoint` on the handle), or to retire the expectation
+/// manually.
+#[derive(Default, Debug, Clone)]
+pub struct ExpectationRef {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Default, Debug)]
+struct Inner {
+    description: String,
+    call_count: usize,
+    is_satisfied: bool,
+    is_saturated: bool,
+    is_retired: bool,
+}
+
+impl ExpectationRef {
+    /// Create a new [`ExpectationRef`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the referenced expectation was called the expected
+    /// amount of times, `false` otherwise.
+    #[must_use]
+    pub fn is_satisfied(&self) -> bool {
+        self.inner.lock().is_satisfied
+    }
+
+    /// Returns `true` if the referenced expectation has reached the upper bound
+    /// of its expected call count, `false` otherwise.
+    #[must_use]
+    pub fn is_saturated(&self) -> bool {
+        self.inner.lock().is_saturated
+    }
+
+    /// Returns the number of calls the referenced expectation has handled.
+    #[must_use]
+    pub fn call_count(&self) -> usize {
+        self.inner.lock().call_count
+    }
+
+    /// Returns `true` if the referenced expectation was retired using
+    /// [`retire`](Self::retire), `false` otherwise.
+    #[must_use]
+    pub fn is_retired(&self) -> bool {
+        self.inner.lock().is_retired
+    }
+
+    /// Returns `true` if the referenced expectation is satisfied or retired,
+    /// `false` otherwise.
+    ///
+    /// Expectations that depend on the referenced one (using `after`) are only
+    /// active if this returns `true`.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        let inner = self.inner.lock();
+
+        inner.is_satisfied || inner.is_retired
+    }
+
+    /// Retire the referenced expectation.
+    ///
+    /// A retired expectation is no longer considered if the mocked method is
+    /// called, and it is not verified if the expectations of the mocked object
+    /// are checked. Expectations that depend on it (using `after`) are no
+    /// longer blocked by it.
+    pub fn retire(&self) {
+        self.inner.lock().is_retired = true;
+    }
+
+    /// Update the state of the reference using the [`Times`] of the referenced
+    /// expectation.
+    pub fn update(&self, times: &Times) {
+        let mut inner = self.inner.lock();
+
+        inner.call_count = times.count.load(Ordering::Relaxed);
+        inner.is_satisfied = times.is_ready();
+        inner.is_saturated = times.is_done();
+    }
+
+    /// Set the description of the referenced expectation.
+    pub fn set_description(&self, value: String) {
+        self.inner.lock().description = value;
+    }
+}
+
+impl Display for ExpectationRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.inner.lock().description)
+    }
+}
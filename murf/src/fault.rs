@@ -0,0 +1,90 @@
+//! The [`fault`](self) module implements the types that are used to keep track
+//! of the faults that were injected by the fault injection actions (like
+//! [`fail_nth`](crate::action::fail_nth)).
+
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+/// A fault that was injected into a call to a mocked method.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Fault {
+    /// Name of the method the fault was injected into.
+    pub method: &'static str,
+
+    /// Number of the call to the fault injection action (starting at 1) the
+    /// fault was injected for.
+    pub call: usize,
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} (call {})", self.method, self.call)
+    }
+}
+
+/// Log of the faults that were injected into the calls of a mocked object.
+///
+/// The log of a mocked object can be queried using the `faults` and
+/// `fault_count` methods of the handle. Faults injected into calls to
+/// associated functions are not recorded.
+#[derive(Default, Debug, Clone)]
+pub struct FaultLog {
+    faults: Arc<Mutex<Vec<Fault>>>,
+}
+
+impl FaultLog {
+    /// Get the faults that were injected so far.
+    #[must_use]
+    pub fn faults(&self) -> Vec<Fault> {
+        self.faults.lock().clone()
+    }
+
+    /// Get the number of faults that were injected so far.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.faults.lock().len()
+    }
+
+    /// Remove all faults from the log.
+    pub fn clear(&self) {
+        self.faults.lock().clear();
+    }
+
+    /// Use this log to record faults that are injected by actions of the passed
+    /// `method` on the current thread, until the returned guard is dropped.
+    #[must_use]
+    pub fn enter(&self, method: &'static str) -> FaultLogGuard {
+        CURRENT_FAULT_LOG.with(|stack| stack.borrow_mut().push((method, self.clone())));
+
+        FaultLogGuard(())
+    }
+}
+
+/// Guard that is returned by [`FaultLog::enter`].
+#[derive(Debug)]
+pub struct FaultLogGuard(());
+
+impl Drop for FaultLogGuard {
+    fn drop(&mut self) {
+        CURRENT_FAULT_LOG.with(|stack| stack.borrow_mut().pop());
+    }
+}
+
+/// Record a fault for the passed `call` in the log of the mocked object whose
+/// action is currently executed on this thread.
+///
+/// Does nothing if no action of a mocked object is currently executed.
+pub fn record_fault(call: usize) {
+    CURRENT_FAULT_LOG.with(|stack| {
+        if let Some((method, log)) = stack.borrow().last() {
+            log.faults.lock().push(Fault { method, call });
+        }
+    });
+}
+
+thread_local! {
+    static CURRENT_FAULT_LOG: RefCell<Vec<(&'static str, FaultLog)>> = const { RefCell::new(Vec::new()) };
+}
@@ -0,0 +1,372 @@
+//! The [`history`](self) module implements the types that are used to record
+//! the calls made on a mocked object.
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+use std::time::SystemTime;
+
+use crate::{misc::CapturedArgs, Matcher, TimesRange};
+
+/// Record of a single call that was made on a mocked object.
+///
+/// The records of a mocked object can be queried using the `calls` method of
+/// the handle, or the generated per-method accessors like `as_fuu_calls_fuu`. Calls to associated functions are not
+/// bound to a specific mock object, so they are not recorded.
+#[derive(Debug, Clone)]
+pub struct CallRecord {
+    /// Unique ID of the mocked method (see [`MockedMethod`]).
+    pub type_id: usize,
+
+    /// Name of the called method.
+    pub method: &'static str,
+
+    /// Arguments of the call (formatted using [`Debug`](std::fmt::Debug) if
+    /// the argument implements it, `_` otherwise).
+    pub args: Vec<String>,
+
+    /// Description of the expectation or default behavior that handled the call
+    /// or `None` if the call was not handled by any of them.
+    pub expectation: Option<String>,
+
+    /// Point in time the call was made.
+    pub timestamp: SystemTime,
+
+    /// Copy of the arguments of the call (if recording of the argument values
+    /// is enabled and all arguments could be cloned).
+    ///
+    /// The arguments are stored as tuple of owned values (without the `self`
+    /// argument). References are stored as the referenced type, `&str` as
+    /// [`String`] and `&[T]` as [`Vec<T>`].
+    pub values: CapturedArgs,
+}
+
+impl CallRecord {
+    /// Create a new [`CallRecord`] for the passed method and arguments.
+    #[must_use]
+    pub fn new(type_id: usize, method: &'static str, args: Vec<String>) -> Self {
+        Self {
+            type_id,
+            method,
+            args,
+            expectation: None,
+            timestamp: SystemTime::now(),
+            values: None,
+        }
+    }
+
+    /// Set the copy of the arguments of the call.
+    #[must_use]
+    pub fn with_values(mut self, values: CapturedArgs) -> Self {
+        self.values = values;
+
+        self
+    }
+
+    /// Get the copy of the arguments of the call if it is of type `T`.
+    #[must_use]
+    pub fn values<T: 'static>(&self) -> Option<&T> {
+        self.values.as_ref()?.downcast_ref()
+    }
+}
+
+impl Display for CallRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}({})", self.method, self.args.join(", "))?;
+
+        if let Some(ex) = &self.expectation {
+            write!(f, " handled by {ex}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Recorded calls of a mocked object.
+///
+/// Recording is enabled by default and the number of records is limited to
+/// [`DEFAULT_CAPACITY`](Self::DEFAULT_CAPACITY). Both can be changed using the
+/// `set_record_calls` and `set_call_history_capacity` methods of the handle.
+/// If the capacity is reached, the oldest record is dropped for each new call.
+///
+/// The values of the arguments are recorded as well (if they implement
+/// [`Clone`]), so they can be checked using `assert_called!`. This can be
+/// disabled using the `set_record_args` method of the handle.
+#[derive(Debug, Clone)]
+pub struct CallHistory {
+    records: VecDeque<CallRecord>,
+    disabled: bool,
+    skip_args: bool,
+    capacity: Option<usize>,
+    dropped: usize,
+}
+
+impl CallHistory {
+    /// Default maximum number of records that are stored.
+    pub const DEFAULT_CAPACITY: usize = 1000;
+
+    /// Create a new empty [`CallHistory`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if new calls are recorded, `false` otherwise.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        !self.disabled && self.capacity != Some(0)
+    }
+
+    /// Enable or disable the recording of new calls.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.disabled = !enabled;
+    }
+
+    /// Returns `true` if the values of the arguments of new calls are recorded,
+    /// `false` otherwise.
+    #[must_use]
+    pub fn records_args(&self) -> bool {
+        !self.skip_args
+    }
+
+    /// Enable or disable the recording of the values of the arguments of new calls.
+    pub fn set_record_args(&mut self, enabled: bool) {
+        self.skip_args = !enabled;
+    }
+
+    /// Get the maximum number of records that are stored.
+    #[must_use]
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Set the maximum number of records that are stored (`None` for no
+    /// limit). If there are more records already, the oldest ones are dropped.
+    pub fn set_capacity(&mut self, capacity: Option<usize>) {
+        self.capacity = capacity;
+
+        if let Some(capacity) = capacity {
+            while self.records.len() > capacity {
+                self.records.pop_front();
+                self.dropped += 1;
+            }
+        }
+    }
+
+    /// Add a new record to the history.
+    ///
+    /// Returns the index of the record that can be passed to
+    /// [`get_mut`](Self::get_mut), or `None` if the record was not stored
+    /// because recording is disabled.
+    pub fn push(&mut self, record: CallRecord) -> Option<usize> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        if self.capacity.is_some_and(|c| self.records.len() >= c) {
+            self.records.pop_front();
+            self.dropped += 1;
+        }
+
+        self.records.push_back(record);
+
+        Some(self.dropped + self.records.len() - 1)
+    }
+
+    /// Get the record with the passed `index` (returned by [`push`](Self::push))
+    /// if it is still stored.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut CallRecord> {
+        self.records.get_mut(index.checked_sub(self.dropped)?)
+    }
+
+    /// Returns an iterator over all stored records (oldest first).
+    pub fn iter(&self) -> impl Iterator<Item = &CallRecord> + '_ {
+        self.records.iter()
+    }
+
+    /// Returns the number of stored records.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if no records are stored, `false` otherwise.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Remove all stored records.
+    pub fn clear(&mut self) {
+        self.dropped += self.records.len();
+        self.records.clear();
+    }
+}
+
+impl Default for CallHistory {
+    fn default() -> Self {
+        Self {
+            records: VecDeque::new(),
+            disabled: false,
+            skip_args: false,
+            capacity: Some(Self::DEFAULT_CAPACITY),
+            dropped: 0,
+        }
+    }
+}
+
+/// Marker that identifies a specific method of a mocked object.
+///
+/// For each mocked method a `Method` type is generated inside the module that
+/// contains the expectation types of the method. It is used to filter the
+/// recorded calls of this method (see the generated per-method accessors like
+/// `as_fuu_calls_fuu` of the handle).
+pub trait MockedMethod {
+    /// Returns the unique ID of the mocked method.
+    fn type_id() -> usize;
+}
+
+/// Assertion that checks the recorded calls of a mocked method against a
+/// [`Matcher`] after the code under test has been executed.
+///
+/// Use the `assert_called!` macro to create and verify an assertion.
+pub struct CallAssertion<T> {
+    method: &'static str,
+    calls: Vec<CallRecord>,
+    matcher: Option<Box<dyn Matcher<T>>>,
+    times: TimesRange,
+    description: Option<String>,
+}
+
+impl<T: 'static> CallAssertion<T> {
+    /// Create a new [`CallAssertion`] for the passed `method` and its recorded `calls`.
+    #[must_use]
+    pub fn new(method: &'static str, calls: Vec<CallRecord>) -> Self {
+        Self {
+            method,
+            calls,
+            matcher: None,
+            times: (1..).into(),
+            description: None,
+        }
+    }
+
+    /// Add a description to the assertion.
+    #[must_use]
+    pub fn description<S: Into<String>>(mut self, value: S) -> Self {
+        self.description = Some(value.into());
+
+        self
+    }
+
+    /// Set the [`Matcher`] the arguments of the recorded calls are checked with.
+    #[must_use]
+    pub fn with<M: Matcher<T> + 'static>(mut self, matcher: M) -> Self {
+        self.matcher = Some(Box::new(matcher));
+
+        self
+    }
+
+    /// Set the expected number of matching calls (default is `1..`).
+    #[must_use]
+    pub fn times<R: Into<TimesRange>>(mut self, range: R) -> Self {
+        self.times = range.into();
+
+        self
+    }
+
+    /// Check the recorded calls against the assertion.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message that describes the failed assertion and the recorded
+    /// calls that came closest to matching.
+    pub fn try_verify(&self) -> Result<(), String> {
+        let mut scored = self
+            .calls
+            .iter()
+            .map(|call| {
+                let score = match (&self.matcher, call.values::<T>()) {
+                    (None, _) => Some((true, 0)),
+                    (Some(m), Some(values)) => Some((m.matches(values), m.match_count(values))),
+                    (Some(_), None) => None,
+                };
+
+                (call, score)
+            })
+            .collect::<Vec<_>>();
+
+        let count = scored
+            .iter()
+            .filter(|(_, score)| matches!(score, Some((true, _))))
+            .count();
+        if self.times.contains(count) {
+            return Ok(());
+        }
+
+        scored.sort_by_key(|(_, score)| std::cmp::Reverse(score.map_or(0, |(_, n)| n)));
+
+        let mut msg = String::new();
+        let _ = write!(msg, "Expected call to {}", self.method);
+        if let Some(m) = &self.matcher {
+            let _ = write!(msg, "({m})");
+        }
+        if let Some(d) = &self.description {
+            let _ = write!(msg, " {d}");
+        }
+        let _ = writeln!(msg);
+        let _ = writeln!(msg, "    Expected calls:      {}", self.times);
+        let _ = writeln!(msg, "    Matching calls:      {count}");
+        let _ = writeln!(msg, "Recorded calls (closest match first):");
+
+        if scored.is_empty() {
+            let _ = writeln!(msg, "    none");
+        }
+
+        for (call, score) in scored {
+            let _ = writeln!(msg, "- {call}");
+
+            match score {
+                Some((true, _)) => {
+                    let _ = writeln!(msg, "    Argument matcher:    ok");
+                }
+                Some((false, n)) => {
+                    let _ = writeln!(msg, "    Argument matcher:    not ok ({n} matching)");
+                }
+                None => {
+                    let _ = writeln!(
+                        msg,
+                        "    Argument matcher:    arguments were not recorded (recording of the arguments was disabled, or they do not implement `Clone`)"
+                    );
+                }
+            }
+        }
+
+        Err(msg)
+    }
+
+    /// Check the recorded calls against the assertion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of matching calls does not fit the expected number of calls.
+    pub fn verify(&self) {
+        if let Err(msg) = self.try_verify() {
+            println!();
+            println!("{msg}");
+
+            panic!("Call assertion for {} failed", self.method);
+        }
+    }
+}
+
+impl<T> Debug for CallAssertion<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("CallAssertion")
+            .field("method", &self.method)
+            .field("calls", &self.calls)
+            .field("matcher", &self.matcher.is_some())
+            .field("times", &self.times)
+            .field("description", &self.description)
+            .finish()
+    }
+}
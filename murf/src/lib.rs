@@ -12,22 +12,43 @@
 #![allow(clippy::module_name_repetitions, clippy::needless_doctest_main)]
 
 pub mod action;
+pub mod closure;
+pub mod expectation_ref;
+pub mod fault;
+pub mod history;
 pub mod local_context;
+pub mod match_order;
 pub mod matcher;
 pub mod misc;
+pub mod policy;
 pub mod sequence;
+pub mod slot;
+pub mod sync;
 pub mod times;
 pub mod types;
+pub mod verification;
 
 #[cfg(doc)]
 pub mod example;
 
-pub use murf_macros::{expect_call, expect_method_call, mock};
+pub use murf_macros::{
+    assert_called, automock, expect_call, expect_method_call, mock, mock_fn, mockable_fn, on_call,
+    on_method_call,
+};
 pub use once_cell::sync::Lazy;
 
 pub use action::Action;
+pub use closure::{mock_closure, ClosureHandle};
+pub use expectation_ref::ExpectationRef;
+pub use fault::{Fault, FaultLog};
+pub use history::{CallAssertion, CallHistory, CallRecord, MockedMethod};
 pub use local_context::LocalContext;
+pub use match_order::MatchOrder;
 pub use matcher::Matcher;
 pub use misc::{next_type_id, Expectation, Pointee, Pointer};
+pub use policy::Policy;
 pub use sequence::{InSequence, Sequence, SequenceHandle};
+pub use slot::Slot;
+pub use sync::{Barrier, Latch};
 pub use times::{Times, TimesRange};
+pub use verification::VerificationReport;
@@ -4,11 +4,12 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Weak;
 
 use parking_lot::Mutex;
 
-use crate::Expectation;
+use crate::{Expectation, MatchOrder};
 
 /// Type that can be used to manage and store call expectations to a associated
 /// or static function in a thread local context. If no [`LocalContext`] is
@@ -21,13 +22,16 @@ use crate::Expectation;
 #[must_use]
 #[derive(Debug)]
 pub struct LocalContext {
+    id: usize,
     _marker: PhantomData<()>,
 }
 
 /// Actual state that is stored for the [`LocalContext`].
 #[derive(Debug)]
 pub struct Inner {
+    id: usize,
     parent: Option<Box<Inner>>,
+    match_order: MatchOrder,
     expectations: HashMap<usize, Vec<WeakException>>,
 }
 
@@ -36,21 +40,51 @@ type WeakException = Weak<Mutex<Box<dyn Expectation + Send + Sync + 'static>>>;
 impl LocalContext {
     /// Create a new [`LocalContext`] instance.
     pub fn new() -> Self {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
         CURRENT_CONTEXT.with(|cell| {
             let mut cell = cell.borrow_mut();
             let parent = cell.take().map(Box::new);
+            let match_order = parent
+                .as_ref()
+                .map_or_else(MatchOrder::default, |p| p.match_order);
 
             *cell = Some(Inner {
+                id,
                 parent,
+                match_order,
                 expectations: HashMap::new(),
             });
         });
 
         Self {
+            id,
             _marker: PhantomData,
         }
     }
 
+    /// Set the [`MatchOrder`] that is used for the expectations of associated
+    /// functions while this context is the current one.
+    ///
+    /// Contexts that are created later on inherit the match order of the
+    /// current context.
+    pub fn set_match_order(&self, match_order: MatchOrder) {
+        CURRENT_CONTEXT.with(|cell| {
+            let mut inner = cell.borrow_mut();
+            let mut inner = inner.as_mut();
+
+            while let Some(i) = inner {
+                if i.id == self.id {
+                    i.match_order = match_order;
+
+                    break;
+                }
+
+                inner = i.parent.as_deref_mut();
+            }
+        });
+    }
+
     /// Get the state of the current local context.
     pub fn current() -> Rc<RefCell<Option<Inner>>> {
         CURRENT_CONTEXT.with(Clone::clone)
@@ -73,9 +107,18 @@ impl Drop for LocalContext {
 }
 
 impl Inner {
+    /// Get the [`MatchOrder`] of the current thread local context.
+    #[must_use]
+    pub fn match_order(&self) -> MatchOrder {
+        self.match_order
+    }
+
     /// Return a iterator of expectations that are defined in the current thread local context.
-    pub fn expectations(&self, type_id: usize) -> impl Iterator<Item = &'_ WeakException> + '_ {
-        let parent: Box<dyn Iterator<Item = &WeakException>> = Box::new(
+    pub fn expectations(
+        &self,
+        type_id: usize,
+    ) -> impl DoubleEndedIterator<Item = &'_ WeakException> + '_ {
+        let parent: Box<dyn DoubleEndedIterator<Item = &WeakException>> = Box::new(
             self.parent
                 .as_ref()
                 .into_iter()
@@ -98,6 +141,8 @@ impl Inner {
     }
 }
 
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
 thread_local! {
     static CURRENT_CONTEXT: Rc<RefCell<Option<Inner>>> = Rc::new(RefCell::new(None));
 }
@@ -0,0 +1,73 @@
+//! The [`match_order`](self) module defines the [`MatchOrder`] that is used to
+//! find a suitable expectation for a call.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::iter::Rev;
+
+/// Defines the order the expectations of a mocked method are checked in when
+/// the method is called.
+///
+/// The order can be set for each mocked object using the `set_match_order`
+/// method of the handle. Associated functions are not bound to a specific mock
+/// object, so they use the order of the current [`LocalContext`](crate::LocalContext).
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MatchOrder {
+    /// The expectation that was defined first is checked first.
+    #[default]
+    OldestFirst,
+
+    /// The expectation that was defined last is checked first (like gmock does).
+    ///
+    /// This can be used to override a broad expectation with a more specific
+    /// one later in a test.
+    NewestFirst,
+}
+
+impl MatchOrder {
+    /// Iterate the passed expectations in the order defined by `self`.
+    pub fn apply<I>(self, iter: I) -> MatchOrderIter<I::IntoIter>
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+    {
+        let iter = iter.into_iter();
+
+        match self {
+            Self::OldestFirst => MatchOrderIter::Forward(iter),
+            Self::NewestFirst => MatchOrderIter::Reverse(iter.rev()),
+        }
+    }
+}
+
+/// Iterator returned by [`MatchOrder::apply`].
+#[derive(Debug)]
+pub enum MatchOrderIter<I> {
+    /// Iterate the expectations in the order they were defined.
+    Forward(I),
+
+    /// Iterate the expectations in reverse order.
+    Reverse(Rev<I>),
+}
+
+impl<I> Iterator for MatchOrderIter<I>
+where
+    I: DoubleEndedIterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Forward(iter) => iter.next(),
+            Self::Reverse(iter) => iter.next(),
+        }
+    }
+}
+
+impl Display for MatchOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::OldestFirst => write!(f, "oldest first"),
+            Self::NewestFirst => write!(f, "newest first"),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use parking_lot::Mutex;
+
+use crate::{slot::OwnedArg, Matcher, Slot};
+
+/// Creates a new [`Capture`] matcher that stores the argument in the passed
+/// `slot` if the `inner` matcher matches.
+pub fn capture<T, M>(slot: &Slot<T>, inner: M) -> Capture<T, M> {
+    Capture {
+        slot: slot.clone(),
+        staged: Mutex::new(None),
+        inner,
+    }
+}
+
+/// Implements a [`Matcher`] that forwards the argument to the inner matcher `M`
+/// and stores it in a [`Slot`] if the inner matcher matches.
+///
+/// The argument is only stored if the expectation the matcher belongs to
+/// actually handles the call (see [`Matcher::commit`]).
+#[must_use]
+#[derive(Debug)]
+pub struct Capture<T, M> {
+    slot: Slot<T>,
+    staged: Mutex<Option<T>>,
+    inner: M,
+}
+
+impl<T, M, X> Matcher<X> for Capture<T, M>
+where
+    M: Matcher<X>,
+    X: OwnedArg<T>,
+{
+    fn matches(&self, value: &X) -> bool {
+        let ret = self.inner.matches(value);
+
+        *self.staged.lock() = ret.then(|| value.owned_arg());
+
+        ret
+    }
+
+    fn match_count(&self, value: &X) -> usize {
+        self.inner.match_count(value)
+    }
+
+    fn commit(&self) {
+        self.inner.commit();
+
+        if let Some(value) = self.staged.lock().take() {
+            self.slot.set(value);
+        }
+    }
+}
+
+impl<T, M> Display for Capture<T, M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.inner.fmt(f)
+    }
+}
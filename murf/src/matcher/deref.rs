@@ -23,6 +23,10 @@ where
     fn matches(&self, value: &T) -> bool {
         self.0.matches(&**value)
     }
+
+    fn commit(&self) {
+        self.0.commit();
+    }
 }
 
 impl<M> Display for Deref<M>
@@ -30,6 +30,10 @@ where
 
         self.0.matches(value)
     }
+
+    fn commit(&self) {
+        self.0.commit();
+    }
 }
 
 impl<M> Display for Inspect<M>
@@ -46,7 +50,7 @@ struct FormatHelper<'a, M, T> {
     value: &'a T,
 }
 
-impl<'a, M, T> Display for FormatHelper<'a, M, T>
+impl<M, T> Display for FormatHelper<'_, M, T>
 where
     M: Matcher<T>,
     T: Debug,
@@ -2,6 +2,7 @@
 //! to check the arguments of a call expectation.
 
 mod any;
+mod capture;
 mod closure;
 mod compare;
 mod deref;
@@ -14,6 +15,7 @@ mod string;
 use std::fmt::Display;
 
 pub use any::{any, Any};
+pub use capture::{capture, Capture};
 pub use closure::{closure, Closure};
 pub use compare::{eq, ge, gt, le, lt, ne, Eq, Ge, Gt, Le, Lt, Ne};
 pub use deref::{deref, Deref};
@@ -33,4 +35,22 @@ pub trait Matcher<T>: Display {
     /// Returns `true` if the passed `value` matches the expectations, `false`
     /// otherwise.
     fn matches(&self, value: &T) -> bool;
+
+    /// Returns the number of (sub-)values of the passed `value` that match the
+    /// expectations.
+    ///
+    /// This is used to find the recorded calls that came closest to matching
+    /// if a call assertion fails.
+    fn match_count(&self, value: &T) -> usize {
+        usize::from(self.matches(value))
+    }
+
+    /// Called once the expectation the matcher belongs to was selected to
+    /// handle the call whose arguments were passed to the last call of
+    /// [`matches`](Self::matches).
+    ///
+    /// Matchers are also checked for calls their expectation does not handle
+    /// (because its call count is exhausted, for example), so matchers that
+    /// store the matched value (like [`capture`]) only do so in this method.
+    fn commit(&self) {}
 }
@@ -12,8 +12,8 @@ pub fn multi<T>(inner: T) -> Multi<T> {
 /// Implements a [`Matcher`] that checks a tuple of parameters against the passed
 /// inner tuple of matchers `T`.
 ///
-/// `T` has to be a valid tuple between two and ten arguments:
-/// - `(T1, T2)`
+/// `T` has to be a valid tuple between one and ten arguments:
+/// - `(T1,)`
 /// - `(T1, ..., T10)`
 #[must_use]
 #[derive(Debug)]
@@ -21,24 +21,38 @@ pub struct Multi<T>(T);
 
 macro_rules! impl_multi {
     (($( $arg_name:ident: $arg_type:ident ),+) => ($( $matcher_name:ident: $matcher_type:ident ),+)) => {
-        #[allow(unused_parens)]
-        impl<$( $arg_type ),+  $( , $matcher_type )+> Matcher<($( $arg_type ),+)> for Multi<($( $matcher_type ),+)>
+        impl<$( $arg_type ),+  $( , $matcher_type )+> Matcher<($( $arg_type, )+)> for Multi<($( $matcher_type, )+)>
         where
             $(
                 $matcher_type: Matcher<$arg_type>,
             )+
         {
-            fn matches(&self, ($( $arg_name ),+): &($( $arg_type ),+)) -> bool {
-                let Self(($( $matcher_name ),+)) = self;
+            fn matches(&self, ($( $arg_name, )+): &($( $arg_type, )+)) -> bool {
+                let Self(($( $matcher_name, )+)) = self;
 
                 $(
                     $matcher_name.matches($arg_name)
                 )&&+
             }
+
+            fn match_count(&self, ($( $arg_name, )+): &($( $arg_type, )+)) -> usize {
+                let Self(($( $matcher_name, )+)) = self;
+
+                0 $(
+                    + usize::from($matcher_name.matches($arg_name))
+                )+
+            }
+
+            fn commit(&self) {
+                let Self(($( $matcher_name, )+)) = self;
+
+                $(
+                    $matcher_name.commit();
+                )+
+            }
         }
 
-        #[allow(unused_parens)]
-        impl<$( $matcher_type ),+> Display for Multi<($( $matcher_type ),+)>
+        impl<$( $matcher_type ),+> Display for Multi<($( $matcher_type, )+)>
         where
             $(
                 $matcher_type: Display,
@@ -46,7 +60,7 @@ macro_rules! impl_multi {
         {
             fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
                 let mut first = true;
-                let Self(($( $matcher_name ),+)) = self;
+                let Self(($( $matcher_name, )+)) = self;
 
                 $(
                     if !take(&mut first) {
@@ -62,6 +76,7 @@ macro_rules! impl_multi {
     };
 }
 
+impl_multi!((a0: T0) => (m0: M0));
 impl_multi!((a0: T0, a1: T1) => (m0: M0, m1: M1));
 impl_multi!((a0: T0, a1: T1, a2: T2) => (m0: M0, m1: M1, m2: M2));
 impl_multi!((a0: T0, a1: T1, a2: T2, a3: T3) => (m0: M0, m1: M1, m2: M2, m3: M3));
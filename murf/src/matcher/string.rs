@@ -60,19 +60,19 @@ impl_str_matcher!(
     str::starts_with,
     "StartsWith({})",
     "Create a new [`StartsWith`] matcher, that matches any kind of string, that starts with the passed `pattern`.",
-    "Implements a [`Matcher'] that matches any kind of string, that starts with the passed pattern."
+    "Implements a [`Matcher`] that matches any kind of string, that starts with the passed pattern."
 );
 impl_str_matcher!(
     EndsWith,
     str::ends_with,
     "EndsWith({})",
     "Create a new [`EndsWith`] matcher, that matches any kind of string, that ends with the passed `pattern`.",
-    "Implements a [`EndsWith'] that matches any kind of string, that starts with the passed pattern."
+    "Implements a [`EndsWith`] that matches any kind of string, that starts with the passed pattern."
 );
 impl_str_matcher!(
     Contains,
     str::contains,
     "Contains({})",
     "Create a new [`Contains`] matcher, that matches any kind of string, that contains the passed `pattern`.",
-    "Implements a [`Contains'] that matches any kind of string, that starts with the passed pattern."
+    "Implements a [`Contains`] that matches any kind of string, that starts with the passed pattern."
 );
@@ -1,14 +1,18 @@
 //! The [`misc`](self) crate contains different helper types and traits.
 
+use std::any::Any;
 use std::borrow::Borrow;
 use std::cell::RefCell;
-use std::fmt::Display;
+use std::fmt::{Debug, Display, Write};
+use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::{
     atomic::{AtomicUsize, Ordering},
     Arc, Mutex,
 };
 
+use crate::{slot::OwnedArg, ExpectationRef, SequenceHandle, Times};
+
 /// Helper type that is used to the values a pointer like type is pointing to.
 ///
 /// This is mostly used in the [`ReturnPointee`](crate::action::ReturnPointee) type.
@@ -72,15 +76,213 @@ pub trait Expectation: Display {
     /// Ready means that the expectation was executed the expected amount of times.
     fn is_ready(&self) -> bool;
 
+    /// Returns `true` if this expectation is retired, `false` otherwise.
+    ///
+    /// Retired expectations are no longer considered if a call to the mocked
+    /// object is made.
+    fn is_retired(&self) -> bool;
+
     /// Mark this expectation as done.
     ///
     /// Done means that this expectation has been finished and will not called again.
     fn set_done(&self);
 
+    /// Get the [`Times`] of the expectation.
+    fn times(&self) -> &Times;
+
+    /// Get the sequences the expectation is part of.
+    fn sequences(&self) -> &[SequenceHandle];
+
     /// Get the type signature of the expectation.
     fn type_signature(&self) -> &'static str;
 }
 
+/// Helper type that is used to format the arguments of a call using
+/// [`Debug`] if the argument implements it.
+///
+/// Use [`DebugArgFormat`] and [`DebugArgFallback`] and call `(&&DebugArg(&value)).format_arg()`
+/// to get the formatted value.
+#[derive(Debug)]
+pub struct DebugArg<'a, T: ?Sized>(pub &'a T);
+
+/// Formats a [`DebugArg`] using the [`Debug`] implementation of the argument.
+pub trait DebugArgFormat {
+    /// Get the formatted argument.
+    fn format_arg(&self) -> String;
+}
+
+impl<T: Debug + ?Sized> DebugArgFormat for &DebugArg<'_, T> {
+    fn format_arg(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// Formats a [`DebugArg`] of an argument that does not implement [`Debug`].
+pub trait DebugArgFallback {
+    /// Get the formatted argument.
+    fn format_arg(&self) -> String;
+}
+
+impl<T: ?Sized> DebugArgFallback for DebugArg<'_, T> {
+    fn format_arg(&self) -> String {
+        "_".into()
+    }
+}
+
+/// Helper type that is used to capture an owned copy of the arguments of a
+/// call if all arguments can be converted into the recorded types `R` (see
+/// [`OwnedArg`]) that implement [`Send`] and [`Sync`].
+///
+/// Use [`CaptureArgsClone`] and [`CaptureArgsFallback`] and call
+/// `(&&CaptureArgs::<_, (A, B)>::new(&(&a, &b))).capture_args()` to get the
+/// captured values. The arguments are captured as tuple (`()` for no arguments).
+#[derive(Debug)]
+pub struct CaptureArgs<'a, T, R>(pub &'a T, pub PhantomData<R>);
+
+impl<'a, T, R> CaptureArgs<'a, T, R> {
+    /// Create a new [`CaptureArgs`] instance for the passed arguments.
+    #[must_use]
+    pub fn new(args: &'a T) -> Self {
+        Self(args, PhantomData)
+    }
+}
+
+/// Captured arguments of a call.
+pub type CapturedArgs = Option<Arc<dyn Any + Send + Sync>>;
+
+/// Captures the arguments of a [`CaptureArgs`] by converting them into owned values.
+pub trait CaptureArgsClone {
+    /// Get the captured arguments.
+    fn capture_args(&self) -> CapturedArgs;
+}
+
+/// Captures the arguments of a [`CaptureArgs`] that could not be converted.
+pub trait CaptureArgsFallback {
+    /// Get the captured arguments.
+    fn capture_args(&self) -> CapturedArgs;
+}
+
+impl<T, R> CaptureArgsFallback for CaptureArgs<'_, T, R> {
+    fn capture_args(&self) -> CapturedArgs {
+        None
+    }
+}
+
+impl CaptureArgsClone for &CaptureArgs<'_, (), ()> {
+    fn capture_args(&self) -> CapturedArgs {
+        Some(Arc::new(()))
+    }
+}
+
+macro_rules! impl_capture_args {
+    ($( $arg_name:ident: $arg_type:ident => $rec_type:ident ),+) => {
+        impl<$( $arg_type, $rec_type ),+> CaptureArgsClone for &CaptureArgs<'_, ($( &$arg_type, )+), ($( $rec_type, )+)>
+        where
+            $(
+                $arg_type: OwnedArg<$rec_type>,
+                $rec_type: Send + Sync + 'static,
+            )+
+        {
+            fn capture_args(&self) -> CapturedArgs {
+                let ($( $arg_name, )+) = self.0;
+
+                Some(Arc::new(($( <$arg_type as OwnedArg<$rec_type>>::owned_arg(*$arg_name), )+)))
+            }
+        }
+    };
+}
+
+impl_capture_args!(a0: T0 => R0);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1, a2: T2 => R2);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1, a2: T2 => R2, a3: T3 => R3);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1, a2: T2 => R2, a3: T3 => R3, a4: T4 => R4);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1, a2: T2 => R2, a3: T3 => R3, a4: T4 => R4, a5: T5 => R5);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1, a2: T2 => R2, a3: T3 => R3, a4: T4 => R4, a5: T5 => R5, a6: T6 => R6);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1, a2: T2 => R2, a3: T3 => R3, a4: T4 => R4, a5: T5 => R5, a6: T6 => R6, a7: T7 => R7);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1, a2: T2 => R2, a3: T3 => R3, a4: T4 => R4, a5: T5 => R5, a6: T6 => R6, a7: T7 => R7, a8: T8 => R8);
+impl_capture_args!(a0: T0 => R0, a1: T1 => R1, a2: T2 => R2, a3: T3 => R3, a4: T4 => R4, a5: T5 => R5, a6: T6 => R6, a7: T7 => R7, a8: T8 => R8, a9: T9 => R9);
+
+/// Marker trait that is implemented for the [`Method`](crate::MockedMethod)
+/// types of the mocked methods whose calls can be checked by `assert_called!`.
+#[diagnostic::on_unimplemented(
+    message = "`assert_called!` is not supported for `{Self}`",
+    note = "only methods whose arguments are owned types or references to owned types (no generic types or `impl Trait`) can be checked"
+)]
+pub trait AssertCalledSupported {}
+
+/// Check if an expectation with the passed call count (`times`), `sequences`
+/// and prerequisites (`after`) is able to handle a new call.
+///
+/// The result of each check is written to `msg`, which is printed if no
+/// suitable expectation was found for a call. This is used by the mocked
+/// objects as well as the mocked closures.
+pub fn check_call_state(
+    msg: &mut String,
+    times: &Times,
+    sequences: &[SequenceHandle],
+    after: &[ExpectationRef],
+) -> bool {
+    let mut is_valid = true;
+
+    /* is done? */
+    if times.is_done() {
+        let _ = writeln!(msg, "    Call count:          done");
+
+        is_valid = false;
+    } else if times.is_ready() {
+        let _ = writeln!(msg, "    Call count:          ready");
+    } else {
+        let _ = writeln!(msg, "    Call count:          ok");
+    }
+
+    /* is active? */
+    for seq_handle in sequences {
+        let s = seq_handle.sequence_id().to_string();
+
+        if seq_handle.is_done() {
+            is_valid = false;
+
+            let _ = writeln!(msg, "    Sequence #{}:{:>2$}done", s, "", 10 - s.len());
+        } else if seq_handle.is_active() {
+            let _ = writeln!(msg, "    Sequence #{}:{:>2$}active", s, "", 10 - s.len());
+        } else {
+            is_valid = false;
+
+            let _ = writeln!(
+                msg,
+                "    Sequence #{}:{:>2$}not active",
+                s,
+                "",
+                10 - s.len()
+            );
+            let _ = writeln!(msg, "        has unsatisfied expectations");
+
+            for ex in seq_handle.unsatisfied() {
+                let _ = writeln!(msg, "          - {ex}");
+            }
+        }
+    }
+
+    /* prerequisites satisfied? (retired prerequisites do not block anymore) */
+    if !after.is_empty() {
+        if after.iter().all(ExpectationRef::is_done) {
+            let _ = writeln!(msg, "    Prerequisites:       ok");
+        } else {
+            is_valid = false;
+
+            let _ = writeln!(msg, "    Prerequisites:       not ok");
+            let _ = writeln!(msg, "        has unsatisfied prerequisites");
+
+            for ex in after.iter().filter(|ex| !ex.is_done()) {
+                let _ = writeln!(msg, "          - {ex}");
+            }
+        }
+    }
+
+    is_valid
+}
+
 /// Get the next type id
 pub fn next_type_id() -> usize {
     NEXT_TYPE_ID.fetch_add(1, Ordering::Relaxed)
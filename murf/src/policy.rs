@@ -0,0 +1,28 @@
+//! The [`policy`](self) module defines the [`Policy`] of a mocked object.
+
+/// Defines how a mocked object handles calls of methods that do not have any
+/// expectation (so called uninteresting calls).
+///
+/// Calls of methods that do have expectations, but none of them matches the
+/// call (because the arguments do not match, or the expectations are already
+/// saturated), are unexpected calls. They always raise a panic, independent
+/// of the policy.
+///
+/// The policy can be set for each mocked object using the `set_policy` method
+/// of the handle, or by creating the mock object using `nice()`, `naggy()` or
+/// `strict()`. Associated functions are not bound to a specific mock object,
+/// so they always use [`Policy::Strict`].
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Policy {
+    /// Uninteresting calls are forwarded to the default implementation of the
+    /// mocked type silently.
+    Nice,
+
+    /// Uninteresting calls are forwarded to the default implementation of the
+    /// mocked type, but a warning is printed.
+    Naggy,
+
+    /// Uninteresting calls will raise a panic.
+    #[default]
+    Strict,
+}
@@ -43,9 +43,9 @@ impl Sequence {
 ///   - Inactive:   Other handles before the current one are not fulfilled yet.
 ///   - Active:     The current handle is the one that needs to be processed next.
 ///   - Ready:      The current handle has been called the expected amount of times,
-///                 but may be called more times before marked as done.
+///     but may be called more times before marked as done.
 ///   - Done:       The handle is done and is not expected to be called again in
-///                 the future.
+///     the future.
 #[derive(Debug)]
 pub struct SequenceHandle {
     id: usize,
@@ -122,7 +122,7 @@ impl<'a> Unsatisfied<'a> {
     }
 }
 
-impl<'a> Iterator for Unsatisfied<'a> {
+impl Iterator for Unsatisfied<'_> {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -0,0 +1,144 @@
+//! The [`slot`](self) module implements the [`Slot`] type that is used to
+//! store arguments of a call for later inspection.
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::Pointee;
+
+/// Storage for a value that is captured during a call to a mocked object.
+///
+/// A slot is passed to the [`save_arg`](crate::action::save_arg) or
+/// [`save_args`](crate::action::save_args) action, or the
+/// [`capture`](crate::matcher::capture) matcher, and can be read after the
+/// call has been made. Clones of a slot share the same value.
+#[derive(Debug)]
+pub struct Slot<T> {
+    inner: Arc<Mutex<Option<T>>>,
+}
+
+impl<T> Slot<T> {
+    /// Create a new empty [`Slot`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store the passed `value` in the slot. Any previous value is overwritten.
+    pub fn set(&self, value: T) {
+        *self.inner.lock() = Some(value);
+    }
+
+    /// Take the value out of the slot and leave the slot empty.
+    #[must_use]
+    pub fn take(&self) -> Option<T> {
+        self.inner.lock().take()
+    }
+
+    /// Returns `true` if the slot contains a value, `false` otherwise.
+    #[must_use]
+    pub fn is_set(&self) -> bool {
+        self.inner.lock().is_some()
+    }
+}
+
+impl<T: Clone> Slot<T> {
+    /// Get a copy of the value that is stored in the slot.
+    #[must_use]
+    pub fn get(&self) -> Option<T> {
+        self.inner.lock().clone()
+    }
+}
+
+impl<T> Default for Slot<T> {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl<T> Clone for Slot<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone> Pointee<T> for Slot<T> {
+    /// # Panics
+    ///
+    /// Panics if the slot is empty.
+    fn get(&self) -> T {
+        Slot::get(self).expect("Slot is empty")
+    }
+}
+
+/// Converts an argument of a call into the value that is stored in a [`Slot`].
+///
+/// This is implemented for any [`Clone`] type, for references to any [`Clone`]
+/// type, for string and slice references (stored as [`String`] and [`Vec`]),
+/// and for path, OS string and C string references (stored as [`PathBuf`],
+/// [`OsString`] and [`CString`]).
+pub trait OwnedArg<T> {
+    /// Get the owned value of the argument.
+    fn owned_arg(&self) -> T;
+}
+
+impl<T: Clone> OwnedArg<T> for T {
+    fn owned_arg(&self) -> T {
+        self.clone()
+    }
+}
+
+impl<T: Clone> OwnedArg<T> for &T {
+    fn owned_arg(&self) -> T {
+        (*self).clone()
+    }
+}
+
+impl<T: Clone> OwnedArg<T> for &mut T {
+    fn owned_arg(&self) -> T {
+        (**self).clone()
+    }
+}
+
+impl OwnedArg<String> for &str {
+    fn owned_arg(&self) -> String {
+        (*self).to_owned()
+    }
+}
+
+impl<T: Clone> OwnedArg<Vec<T>> for &[T] {
+    fn owned_arg(&self) -> Vec<T> {
+        self.to_vec()
+    }
+}
+
+impl<T: Clone> OwnedArg<Vec<T>> for &mut [T] {
+    fn owned_arg(&self) -> Vec<T> {
+        self.to_vec()
+    }
+}
+
+impl OwnedArg<PathBuf> for &Path {
+    fn owned_arg(&self) -> PathBuf {
+        self.to_path_buf()
+    }
+}
+
+impl OwnedArg<OsString> for &OsStr {
+    fn owned_arg(&self) -> OsString {
+        self.to_os_string()
+    }
+}
+
+impl OwnedArg<CString> for &CStr {
+    fn owned_arg(&self) -> CString {
+        (*self).to_owned()
+    }
+}
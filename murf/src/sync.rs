@@ -0,0 +1,122 @@
+//! The [`sync`](self) module implements synchronization primitives that can be
+//! used together with the blocking actions (like [`wait_latch`](crate::action::wait_latch))
+//! to control the timing of calls to mocked objects from different threads.
+
+use std::sync::{Arc, Barrier as StdBarrier};
+
+use parking_lot::{Condvar, Mutex};
+
+use crate::types::Duration;
+
+/// Barrier that blocks a fixed number of threads until all of them have
+/// reached it.
+///
+/// This is a cloneable version of [`std::sync::Barrier`]. Clones of a barrier
+/// refer to the same barrier.
+#[derive(Debug, Clone)]
+pub struct Barrier {
+    inner: Arc<StdBarrier>,
+}
+
+impl Barrier {
+    /// Create a new [`Barrier`] that blocks until `n` threads called
+    /// [`wait`](Self::wait).
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        Self {
+            inner: Arc::new(StdBarrier::new(n)),
+        }
+    }
+
+    /// Block the current thread until all threads have reached the barrier.
+    pub fn wait(&self) {
+        self.inner.wait();
+    }
+}
+
+/// Latch that blocks threads until it was counted down to zero.
+///
+/// In contrast to a [`Barrier`] the threads that count down the latch are not
+/// blocked. Once the latch is released it stays released. Clones of a latch
+/// refer to the same latch.
+#[derive(Debug, Clone)]
+pub struct Latch {
+    inner: Arc<LatchInner>,
+}
+
+#[derive(Debug)]
+struct LatchInner {
+    count: Mutex<usize>,
+    condvar: Condvar,
+}
+
+impl Latch {
+    /// Create a new [`Latch`] that is released once [`count_down`](Self::count_down)
+    /// was called `count` times.
+    #[must_use]
+    pub fn new(count: usize) -> Self {
+        Self {
+            inner: Arc::new(LatchInner {
+                count: Mutex::new(count),
+                condvar: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Decrement the count of the latch and release all waiting threads once
+    /// it reaches zero.
+    pub fn count_down(&self) {
+        let mut count = self.inner.count.lock();
+
+        if *count > 0 {
+            *count -= 1;
+
+            if *count == 0 {
+                self.inner.condvar.notify_all();
+            }
+        }
+    }
+
+    /// Release the latch immediately.
+    pub fn release(&self) {
+        *self.inner.count.lock() = 0;
+
+        self.inner.condvar.notify_all();
+    }
+
+    /// Returns `true` if the latch was released, `false` otherwise.
+    #[must_use]
+    pub fn is_released(&self) -> bool {
+        *self.inner.count.lock() == 0
+    }
+
+    /// Block the current thread until the latch is released.
+    pub fn wait(&self) {
+        let mut count = self.inner.count.lock();
+
+        while *count > 0 {
+            self.inner.condvar.wait(&mut count);
+        }
+    }
+
+    /// Block the current thread until the latch is released or the passed
+    /// `timeout` has elapsed.
+    ///
+    /// The `timeout` can be passed as [`std::time::Duration`] or as
+    /// [`Duration`](crate::types::Duration) (like for the [`sleep`](crate::action::sleep) action).
+    ///
+    /// Returns `true` if the latch was released, `false` if the timeout elapsed.
+    pub fn wait_timeout<D: Into<Duration>>(&self, timeout: D) -> bool {
+        let mut count = self.inner.count.lock();
+
+        if *count > 0 {
+            let timeout = timeout.into().0;
+            let _ = self
+                .inner
+                .condvar
+                .wait_while_for(&mut count, |count| *count > 0, timeout);
+        }
+
+        *count == 0
+    }
+}
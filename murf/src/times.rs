@@ -1,6 +1,7 @@
 //! The [`times`](self) module contains different types and helpers to define
 //! how often a call expectation may be called.
 
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::ops::{
     Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
 };
@@ -53,12 +54,21 @@ impl Times {
 ///
 /// Similar to [`RangeBounds`] from the standard library but as struct instead
 /// of trait.
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct TimesRange {
     lower: Bound<usize>,
     upper: Bound<usize>,
 }
 
+impl TimesRange {
+    /// Returns `true` if the passed number of calls is inside the range,
+    /// `false` otherwise.
+    #[must_use]
+    pub fn contains(&self, count: usize) -> bool {
+        (self.lower, self.upper).contains(&count)
+    }
+}
+
 impl Default for TimesRange {
     fn default() -> Self {
         Self {
@@ -77,6 +87,28 @@ impl From<usize> for TimesRange {
     }
 }
 
+impl Display for TimesRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (&self.lower, &self.upper) {
+            (Bound::Included(a), Bound::Included(b)) if a == b => write!(f, "{a}"),
+            (Bound::Unbounded, Bound::Unbounded) => write!(f, "any number"),
+            (lower, upper) => {
+                match lower {
+                    Bound::Included(x) => write!(f, "{x}")?,
+                    Bound::Excluded(x) => write!(f, "{}", x + 1)?,
+                    Bound::Unbounded => (),
+                }
+
+                match upper {
+                    Bound::Included(x) => write!(f, "..={x}"),
+                    Bound::Excluded(x) => write!(f, "..{x}"),
+                    Bound::Unbounded => write!(f, ".."),
+                }
+            }
+        }
+    }
+}
+
 macro_rules! impl_from_range_bounds {
     ($x:ty) => {
         impl From<$x> for TimesRange {
@@ -99,7 +131,7 @@ impl_from_range_bounds!(RangeToInclusive<usize>);
 
 #[cfg(test)]
 mod tests {
-    use super::Times;
+    use super::{Times, TimesRange};
 
     #[test]
     fn number() {
@@ -222,4 +254,13 @@ mod tests {
         assert!(t.is_ready());
         assert!(t.is_done());
     }
+
+    #[test]
+    fn display() {
+        assert_eq!("2", TimesRange::from(2).to_string());
+        assert_eq!("1..", TimesRange::from(1..).to_string());
+        assert_eq!("..3", TimesRange::from(..3).to_string());
+        assert_eq!("1..=3", TimesRange::from(1..=3).to_string());
+        assert_eq!("any number", TimesRange::from(..).to_string());
+    }
 }
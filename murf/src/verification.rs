@@ -0,0 +1,188 @@
+//! The [`verification`](self) module implements the [`VerificationReport`]
+//! that is returned if the expectations of a mocked object are verified.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::sync::atomic::Ordering;
+
+use crate::{Expectation, SequenceHandle, Times, TimesRange};
+
+/// Report of a failed verification of the expectations of a mocked object.
+///
+/// The report is returned by the `try_checkpoint` method of the handle and
+/// lists all expectations that were not fulfilled.
+#[derive(Debug, Clone)]
+pub struct VerificationReport {
+    /// Name of the mocked object the report was created for.
+    pub object: String,
+
+    /// List of expectations that were not fulfilled.
+    pub unfulfilled: Vec<UnfulfilledExpectation>,
+
+    /// Is set to `true` if the expectations could not be verified at all,
+    /// because the mocked object was already locked (for example because the
+    /// verification was started from inside an action of the same object).
+    pub locked: bool,
+}
+
+impl VerificationReport {
+    /// Create a new empty [`VerificationReport`] for the passed `object`.
+    pub fn new<S: Into<String>>(object: S) -> Self {
+        Self {
+            object: object.into(),
+            unfulfilled: Vec::new(),
+            locked: false,
+        }
+    }
+
+    /// Create a new [`VerificationReport`] for the passed `object` that could
+    /// not be verified because it was already locked.
+    pub fn locked<S: Into<String>>(object: S) -> Self {
+        Self {
+            locked: true,
+            ..Self::new(object)
+        }
+    }
+
+    /// Add a new unfulfilled expectation to the report.
+    pub fn push(&mut self, expectation: UnfulfilledExpectation) {
+        self.unfulfilled.push(expectation);
+    }
+
+    /// Returns `true` if the report does not contain any unfulfilled expectation.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.unfulfilled.is_empty()
+    }
+
+    /// Get the headline of the report.
+    #[must_use]
+    pub fn title(&self) -> String {
+        if self.locked {
+            format!(
+                "{} could not be verified: Unable to lock handle",
+                self.object
+            )
+        } else {
+            format!("{} has unfulfilled expectations", self.object)
+        }
+    }
+
+    /// Convert the report into a [`Result`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the report itself as error if it contains at least one
+    /// unfulfilled expectation, or if the object could not be verified.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() && !self.locked {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Display for VerificationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "{}", self.title())?;
+
+        if self.locked {
+            writeln!(f, "Deadlock? Make sure that you do not drop a handle (or call `checkpoint` directly) of the same object inside an action of an expectation.")?;
+        }
+
+        for ex in &self.unfulfilled {
+            write!(f, "{ex}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for VerificationReport {}
+
+/// Expectation that was not fulfilled at the time the [`VerificationReport`]
+/// was created.
+#[derive(Debug, Clone)]
+pub struct UnfulfilledExpectation {
+    /// Human readable description of the expectation.
+    pub description: String,
+
+    /// Expected number of calls.
+    pub times: TimesRange,
+
+    /// Actual number of calls.
+    pub call_count: usize,
+
+    /// State of the sequences the expectation is part of.
+    pub sequences: Vec<SequenceState>,
+}
+
+impl UnfulfilledExpectation {
+    /// Create a new [`UnfulfilledExpectation`] from the passed values.
+    #[must_use]
+    pub fn new(description: String, times: &Times, sequences: &[SequenceHandle]) -> Self {
+        Self {
+            description,
+            times: times.range.clone(),
+            call_count: times.count.load(Ordering::Relaxed),
+            sequences: sequences.iter().map(SequenceState::new).collect(),
+        }
+    }
+
+    /// Create a new [`UnfulfilledExpectation`] from the passed `expectation`.
+    #[must_use]
+    pub fn from_expectation(expectation: &dyn Expectation) -> Self {
+        Self::new(
+            expectation.to_string(),
+            expectation.times(),
+            expectation.sequences(),
+        )
+    }
+}
+
+impl Display for UnfulfilledExpectation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "- {}", self.description)?;
+        writeln!(f, "    Expected calls:      {}", self.times)?;
+        writeln!(f, "    Actual calls:        {}", self.call_count)?;
+
+        for seq in &self.sequences {
+            let s = seq.sequence_id.to_string();
+            let state = if seq.is_done {
+                "done"
+            } else if seq.is_active {
+                "active"
+            } else {
+                "not active"
+            };
+
+            writeln!(f, "    Sequence #{}:{:>2$}{state}", s, "", 10 - s.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// State of a sequence an [`UnfulfilledExpectation`] is part of.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SequenceState {
+    /// Id of the sequence.
+    pub sequence_id: usize,
+
+    /// Whether the expectation is the active one in the sequence.
+    pub is_active: bool,
+
+    /// Whether the expectation is done in the sequence.
+    pub is_done: bool,
+}
+
+impl SequenceState {
+    fn new(handle: &SequenceHandle) -> Self {
+        Self {
+            sequence_id: handle.sequence_id(),
+            is_active: handle.is_active(),
+            is_done: handle.is_done(),
+        }
+    }
+}
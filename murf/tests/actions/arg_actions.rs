@@ -0,0 +1,116 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use murf::{
+    action::{do_all, invoke_arg, invoke_arg_with, return_arg, Return},
+    expect_call, expect_method_call, mock, LocalContext,
+};
+
+type Callback = Box<dyn Fn(String, usize) + Send>;
+
+trait Fuu {
+    fn echo(&self, x: usize) -> usize;
+    fn name(&self, id: usize, name: &str) -> String;
+    fn subscribe(&self, topic: &str, cb: Callback);
+    fn map(&self, f: &dyn Fn(usize) -> usize) -> usize;
+}
+
+trait Echo {
+    fn make(x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn echo(&self, x: usize) -> usize;
+        fn name(&self, id: usize, name: &str) -> String;
+        fn subscribe(&self, topic: &str, cb: Callback);
+        fn map(&self, f: &dyn Fn(usize) -> usize) -> usize;
+    }
+
+    impl Echo for MyStruct {
+        fn make(_x: usize) -> usize;
+    }
+}
+
+#[test]
+fn return_nth_arg() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, echo(_)).will_repeatedly(return_arg::<1>());
+    expect_method_call!(handle as Fuu, name(_, _)).will_once(return_arg::<2>());
+
+    assert_eq!(1, mock.echo(1));
+    assert_eq!(5, mock.echo(5));
+    assert_eq!("fuu", mock.name(1, "fuu"));
+}
+
+#[test]
+fn return_arg_of_single_arg_function() {
+    let _local_context = LocalContext::new();
+    let (handle, _mock) = MyStruct::mock_with_handle();
+
+    expect_call!(handle as Echo, make(_)).will_once(return_arg::<0>());
+
+    assert_eq!(3, MyStructMock::make(3));
+}
+
+#[test]
+fn invoke_callback() {
+    let received = Arc::new(AtomicUsize::new(0));
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, subscribe(_, _))
+        .will_once(invoke_arg::<2, _>(("fuu".to_owned(), 5)));
+
+    let received_clone = received.clone();
+    mock.subscribe(
+        "topic",
+        Box::new(move |msg, value| {
+            assert_eq!("fuu", msg);
+            received_clone.store(value, Ordering::SeqCst);
+        }),
+    );
+
+    assert_eq!(5, received.load(Ordering::SeqCst));
+}
+
+#[test]
+fn invoke_callback_with_produced_values() {
+    let received = Arc::new(AtomicUsize::new(0));
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, subscribe(_, _))
+        .times(2)
+        .will_repeatedly(invoke_arg_with::<2, _>(|| ("bar".to_owned(), 1)));
+
+    for _ in 0..2 {
+        let received = received.clone();
+        mock.subscribe(
+            "topic",
+            Box::new(move |_, value| {
+                received.fetch_add(value, Ordering::SeqCst);
+            }),
+        );
+    }
+
+    assert_eq!(2, received.load(Ordering::SeqCst));
+}
+
+#[test]
+fn invoke_dyn_fn() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, map(_)).will_once(invoke_arg::<1, _>((3,)));
+    expect_method_call!(handle as Fuu, map(_))
+        .will_once(do_all((invoke_arg::<1, _>((4,)), Return(1))));
+
+    assert_eq!(6, mock.map(&|x| x * 2));
+    assert_eq!(1, mock.map(&|x| x * 2));
+}
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+use std::thread::spawn;
+use std::time::{Duration as StdDuration, Instant};
+
+use futures::executor::block_on;
+use murf::{
+    action::{do_all, signal_latch, sleep, wait_barrier, wait_latch, Return},
+    expect_method_call, mock,
+    types::Duration,
+    Barrier, Latch,
+};
+
+trait Sensor {
+    fn read(&self) -> u32;
+    fn status(&self) -> bool;
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MySensor;
+
+    impl Sensor for MySensor {
+        fn read(&self) -> u32;
+        fn status(&self) -> bool;
+    }
+}
+
+trait AsyncSensor {
+    async fn read(&self) -> u32;
+    fn status(&self) -> bool;
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MyAsyncSensor;
+
+    impl AsyncSensor for MyAsyncSensor {
+        async fn read(&self) -> u32;
+        fn status(&self) -> bool;
+    }
+}
+
+trait Device {
+    fn read(&self) -> u32;
+    fn status(&self) -> bool;
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MyDevice {
+        pub latches: Option<(Latch, Latch)>,
+    }
+
+    impl Device for MyDevice {
+        fn read(&self) -> u32 {
+            if let Some((entered, release)) = &self.latches {
+                entered.count_down();
+                release.wait();
+            }
+
+            5
+        }
+
+        fn status(&self) -> bool;
+    }
+}
+
+#[test]
+fn hold_worker_inside_call() {
+    let entered = Latch::new(1);
+    let release = Latch::new(1);
+
+    let (handle, mock) = MySensor::mock_with_handle();
+    let mock = Arc::new(mock);
+
+    expect_method_call!(handle as Sensor, read()).will_once(do_all((
+        signal_latch(&entered),
+        wait_latch(&release),
+        Return(5),
+    )));
+    expect_method_call!(handle as Sensor, status()).will_once(Return(true));
+
+    let worker = {
+        let mock = mock.clone();
+
+        spawn(move || mock.read())
+    };
+
+    entered.wait();
+
+    /* the worker is blocked inside `read`, but the mock is still usable */
+    assert!(mock.status());
+    assert_eq!(2, handle.calls().len());
+    assert!(!release.is_released());
+
+    release.count_down();
+
+    assert_eq!(5, worker.join().unwrap());
+}
+
+#[test]
+fn hold_worker_inside_async_call() {
+    let entered = Latch::new(1);
+    let release = Latch::new(1);
+
+    let (handle, mock) = MyAsyncSensor::mock_with_handle();
+    let mock = Arc::new(mock);
+
+    expect_method_call!(handle as AsyncSensor, read()).will_once(do_all((
+        signal_latch(&entered),
+        wait_latch(&release),
+        Return(5),
+    )));
+    expect_method_call!(handle as AsyncSensor, status()).will_once(Return(true));
+
+    let worker = {
+        let mock = mock.clone();
+
+        spawn(move || block_on(mock.read()))
+    };
+
+    entered.wait();
+
+    /* the action of the async method was detached, so the mock is still usable */
+    assert!(mock.status());
+
+    release.count_down();
+
+    assert_eq!(5, worker.join().unwrap());
+}
+
+#[test]
+fn hold_worker_inside_default_implementation() {
+    let entered = Latch::new(1);
+    let release = Latch::new(1);
+
+    let (handle, mut mock) = MyDevice::mock_with_handle();
+    mock.state.latches = Some((entered.clone(), release.clone()));
+    let mock = Arc::new(mock);
+
+    expect_method_call!(handle as Device, read());
+    expect_method_call!(handle as Device, status()).will_once(Return(true));
+
+    let worker = {
+        let mock = mock.clone();
+
+        spawn(move || mock.read())
+    };
+
+    entered.wait();
+
+    /* the default implementation is executed without locking the mock */
+    assert!(mock.status());
+
+    release.count_down();
+
+    assert_eq!(5, worker.join().unwrap());
+}
+
+#[test]
+fn barrier_for_concurrent_calls() {
+    let barrier = Barrier::new(2);
+
+    let (handle, mock) = MySensor::mock_with_handle();
+    let mock = Arc::new(mock);
+
+    expect_method_call!(handle as Sensor, read())
+        .times(2)
+        .will_repeatedly(do_all((wait_barrier(&barrier), Return(1))));
+
+    let workers = (0..2)
+        .map(|_| {
+            let mock = mock.clone();
+
+            spawn(move || mock.read())
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        assert_eq!(1, worker.join().unwrap());
+    }
+}
+
+#[test]
+fn sleep_inside_call() {
+    let (handle, mock) = MySensor::mock_with_handle();
+
+    expect_method_call!(handle as Sensor, read())
+        .will_once(do_all((sleep(Duration::from_millis(20)), Return(1))));
+
+    let start = Instant::now();
+
+    assert_eq!(1, mock.read());
+    assert!(start.elapsed() >= StdDuration::from_millis(20));
+}
+
+#[test]
+fn latch_timeout() {
+    let latch = Latch::new(2);
+
+    assert!(!latch.wait_timeout(StdDuration::from_millis(1)));
+
+    latch.count_down();
+    latch.count_down();
+
+    assert!(latch.is_released());
+    assert!(latch.wait_timeout(Duration::from_millis(1)));
+}
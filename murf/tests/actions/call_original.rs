@@ -0,0 +1,158 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use murf::{
+    action::{call_original, Action},
+    expect_method_call, mock, on_method_call,
+};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    Timeout,
+}
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error>;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct {
+        pub factor: usize,
+    }
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize {
+            x * self.factor
+        }
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+            buf.fill(1);
+
+            Ok(buf.len())
+        }
+    }
+}
+
+trait Bar {
+    fn add(&self, x: usize) -> usize;
+    fn sub(&self, x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct Calculator;
+
+    impl Bar for Calculator {
+        fn add(&self, x: usize) -> usize {
+            x + 1
+        }
+
+        fn sub(&self, x: usize) -> usize {
+            x - 1
+        }
+    }
+}
+
+#[test]
+fn call_original_directly() {
+    let (handle, mut mock) = MyStruct::mock_with_handle();
+    mock.state.factor = 2;
+
+    expect_method_call!(handle as Fuu, fuu(_)).will_once(call_original());
+
+    assert_eq!(4, mock.fuu(2));
+}
+
+#[test]
+fn wrap_original() {
+    let count = Arc::new(AtomicUsize::new(0));
+
+    let (handle, mut mock) = MyStruct::mock_with_handle();
+    mock.state.factor = 3;
+
+    let count_clone = count.clone();
+    expect_method_call!(handle as Fuu, fuu(_))
+        .times(2)
+        .will_repeatedly(move |args: (&_, usize)| {
+            count_clone.fetch_add(1, Ordering::SeqCst);
+
+            let ret: usize = call_original().exec(args);
+
+            ret + 1
+        });
+
+    assert_eq!(7, mock.fuu(2));
+    assert_eq!(10, mock.fuu(3));
+    assert_eq!(2, count.load(Ordering::SeqCst));
+}
+
+#[test]
+fn fail_on_third_call() {
+    let (handle, mut mock) = MyStruct::mock_with_handle();
+
+    let count = Arc::new(AtomicUsize::new(0));
+    expect_method_call!(handle as Fuu, read(_))
+        .times(3)
+        .will_repeatedly(move |args: (&mut _, &mut [u8])| {
+            if count.fetch_add(1, Ordering::SeqCst) == 2 {
+                Err(Error::Timeout)
+            } else {
+                call_original().exec(args)
+            }
+        });
+
+    let mut buf = [0u8; 4];
+    assert_eq!(Ok(4), mock.read(&mut buf));
+    assert_eq!([1, 1, 1, 1], buf);
+    assert_eq!(Ok(4), mock.read(&mut buf));
+    assert_eq!(Err(Error::Timeout), mock.read(&mut buf));
+}
+
+#[test]
+fn default_behavior_calls_original() {
+    let (handle, mut mock) = MyStruct::mock_with_handle();
+    mock.state.factor = 5;
+
+    on_method_call!(handle as Fuu, fuu(_)).will_by_default(call_original());
+
+    assert_eq!(10, mock.fuu(2));
+}
+
+#[test]
+fn outside_of_mock() {
+    let (_handle, mut mock) = MyStruct::mock_with_handle();
+    mock.state.factor = 3;
+
+    let ret: usize = call_original().exec((&mock, 2));
+
+    assert_eq!(6, ret);
+}
+
+#[test]
+fn builder_methods() {
+    let (handle, mock) = Calculator::mock_with_handle();
+
+    expect_method_call!(handle as Bar, add(_)).will_call_original();
+    expect_method_call!(handle as Bar, sub(_))
+        .times(2)
+        .will_repeatedly_call_original();
+
+    assert_eq!(11, mock.add(10));
+    assert_eq!(9, mock.sub(10));
+    assert_eq!(4, mock.sub(5));
+}
+
+#[test]
+fn default_behavior_builder_method() {
+    let (handle, mock) = Calculator::mock_with_handle();
+
+    on_method_call!(handle as Bar, add(_)).will_by_default_call_original();
+    on_method_call!(handle as Bar, sub(_)).will_by_default_call_original();
+
+    assert_eq!(11, mock.add(10));
+    assert_eq!(9, mock.sub(10));
+}
@@ -0,0 +1,111 @@
+use murf::{
+    action::{cycle, do_all, return_iter, save_arg, seq_actions, set_arg, ActionExt, Return},
+    expect_method_call, mock, Slot,
+};
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+    fn bar(&self, out: &mut usize) -> bool;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize;
+        fn bar(&self, out: &mut usize) -> bool;
+    }
+}
+
+#[test]
+fn do_all_side_effects() {
+    let x = Slot::new();
+    let y = Slot::new();
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_)).will_once(do_all((
+        save_arg::<1, _>(&x),
+        save_arg::<1, _>(&y),
+        Return(4),
+    )));
+
+    assert_eq!(4, mock.fuu(2));
+    assert_eq!(Some(2), x.get());
+    assert_eq!(Some(2), y.get());
+}
+
+#[test]
+fn then() {
+    let x = Slot::new();
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_)).will_once(save_arg::<1, _>(&x).then(Return(1)));
+    expect_method_call!(handle as Fuu, bar(_)).will_once(set_arg::<1, _>(3).then(Return(true)));
+
+    assert_eq!(1, mock.fuu(5));
+    assert_eq!(Some(5), x.get());
+
+    let mut out = 0;
+    assert!(mock.bar(&mut out));
+    assert_eq!(3, out);
+}
+
+#[test]
+fn return_values_from_iter() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_))
+        .times(3)
+        .will_repeatedly(return_iter(vec![1, 2, 3]));
+
+    assert_eq!(1, mock.fuu(0));
+    assert_eq!(2, mock.fuu(0));
+    assert_eq!(3, mock.fuu(0));
+}
+
+#[test]
+#[should_panic(
+    expected = "Action `return_iter` is exhausted: call 3 exceeds the number of provided items"
+)]
+fn return_iter_exhausted() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_)).will_repeatedly(return_iter(vec![1, 2]));
+
+    mock.fuu(0);
+    mock.fuu(0);
+    mock.fuu(0);
+}
+
+#[test]
+fn sequence_of_actions() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, bar(_))
+        .times(2)
+        .will_repeatedly(seq_actions(vec![
+            set_arg::<1, _>(1).then(Return(true)),
+            set_arg::<1, _>(2).then(Return(false)),
+        ]));
+
+    let mut out = 0;
+    assert!(mock.bar(&mut out));
+    assert_eq!(1, out);
+    assert!(!mock.bar(&mut out));
+    assert_eq!(2, out);
+}
+
+#[test]
+fn cycle_actions() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_)).will_repeatedly(cycle(vec![Return(1), Return(2)]));
+
+    assert_eq!(1, mock.fuu(0));
+    assert_eq!(2, mock.fuu(0));
+    assert_eq!(1, mock.fuu(0));
+    assert_eq!(2, mock.fuu(0));
+}
@@ -0,0 +1,89 @@
+use murf::{
+    action::{
+        panic_with, return_default, return_err, return_err_with, return_ok, return_ok_with,
+        return_with,
+    },
+    expect_method_call, mock,
+};
+
+// Error type that does not implement `Clone`.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    Timeout,
+    Io(String),
+}
+
+// Value type that does not implement `Clone`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Frame(Vec<u8>);
+
+trait Fuu {
+    fn read(&self) -> Result<Frame, Error>;
+    fn status(&self) -> Result<u8, Error>;
+    fn values(&self) -> Vec<u8>;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn read(&self) -> Result<Frame, Error>;
+        fn status(&self) -> Result<u8, Error>;
+        fn values(&self) -> Vec<u8>;
+    }
+}
+
+#[test]
+fn ok_and_err() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, status()).will_once(return_ok(1));
+    expect_method_call!(handle as Fuu, read()).will_once(return_err(Error::Timeout));
+
+    assert_eq!(Ok(1), mock.status());
+    assert_eq!(Err(Error::Timeout), mock.read());
+}
+
+#[test]
+fn factories_for_non_clone_types() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, read())
+        .times(2)
+        .will_repeatedly(return_err_with(|| Error::Io("broken pipe".into())));
+
+    assert_eq!(Err(Error::Io("broken pipe".into())), mock.read());
+    assert_eq!(Err(Error::Io("broken pipe".into())), mock.read());
+
+    expect_method_call!(handle as Fuu, read())
+        .times(2)
+        .will_repeatedly(return_ok_with(|| Frame(vec![1, 2])));
+
+    assert_eq!(Ok(Frame(vec![1, 2])), mock.read());
+    assert_eq!(Ok(Frame(vec![1, 2])), mock.read());
+}
+
+#[test]
+fn default_and_fresh_values() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, values()).will_once(return_default());
+    expect_method_call!(handle as Fuu, values())
+        .times(2)
+        .will_repeatedly(return_with(|| vec![1, 2, 3]));
+
+    assert_eq!(Vec::<u8>::new(), mock.values());
+    assert_eq!(vec![1, 2, 3], mock.values());
+    assert_eq!(vec![1, 2, 3], mock.values());
+}
+
+#[test]
+#[should_panic(expected = "device disconnected")]
+fn panic() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, status()).will_once(panic_with("device disconnected"));
+
+    let _ = mock.status();
+}
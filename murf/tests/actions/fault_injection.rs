@@ -0,0 +1,142 @@
+use murf::{
+    action::{fail_every, fail_nth, fail_with_probability, return_err, return_ok},
+    expect_method_call, mock, Fault,
+};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Error {
+    Timeout,
+}
+
+trait Fuu {
+    fn read(&self) -> Result<u8, Error>;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn read(&self) -> Result<u8, Error>;
+    }
+}
+
+#[test]
+fn nth_call() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, read())
+        .times(4)
+        .will_repeatedly(fail_nth(2, return_ok(1), return_err(Error::Timeout)));
+
+    assert_eq!(Ok(1), mock.read());
+    assert_eq!(Err(Error::Timeout), mock.read());
+    assert_eq!(Ok(1), mock.read());
+    assert_eq!(Ok(1), mock.read());
+
+    assert_eq!(1, handle.fault_count());
+    assert_eq!(
+        vec![Fault {
+            method: "<MyStruct as Fuu>::read",
+            call: 2
+        }],
+        handle.faults()
+    );
+}
+
+#[test]
+fn every_kth_call() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, read())
+        .times(6)
+        .will_repeatedly(fail_every(3, return_ok(1), return_err(Error::Timeout)));
+
+    let results = (0..6).map(|_| mock.read()).collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            Ok(1),
+            Ok(1),
+            Err(Error::Timeout),
+            Ok(1),
+            Ok(1),
+            Err(Error::Timeout)
+        ],
+        results
+    );
+    assert_eq!(
+        vec![3, 6],
+        handle.faults().iter().map(|f| f.call).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn probability_is_reproducible() {
+    let run = |seed| {
+        let (handle, mock) = MyStruct::mock_with_handle();
+
+        expect_method_call!(handle as Fuu, read()).will_repeatedly(fail_with_probability(
+            0.5,
+            seed,
+            return_ok(1),
+            return_err(Error::Timeout),
+        ));
+
+        let results = (0..100).map(|_| mock.read()).collect::<Vec<_>>();
+
+        (results, handle.fault_count())
+    };
+
+    let (first, faults) = run(42);
+    let (second, _) = run(42);
+
+    assert_eq!(first, second);
+    assert_eq!(faults, first.iter().filter(|x| x.is_err()).count());
+    assert!(faults > 20 && faults < 80);
+}
+
+#[test]
+fn probability_bounds() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, read())
+        .times(10)
+        .will_repeatedly(fail_with_probability(
+            0.0,
+            1,
+            return_ok(1),
+            return_err(Error::Timeout),
+        ));
+
+    for _ in 0..10 {
+        assert_eq!(Ok(1), mock.read());
+    }
+
+    expect_method_call!(handle as Fuu, read())
+        .times(10)
+        .will_repeatedly(fail_with_probability(
+            1.0,
+            1,
+            return_ok(1),
+            return_err(Error::Timeout),
+        ));
+
+    for _ in 0..10 {
+        assert_eq!(Err(Error::Timeout), mock.read());
+    }
+
+    assert_eq!(10, handle.fault_count());
+}
+
+#[test]
+#[should_panic(expected = "`fail_with_probability` needs a probability between 0.0 and 1.0")]
+fn probability_out_of_range() {
+    let _ = fail_with_probability(1.5, 1, return_ok(1), return_err(Error::Timeout));
+}
+
+#[test]
+#[should_panic(expected = "`fail_with_probability` needs a probability between 0.0 and 1.0")]
+fn probability_nan() {
+    let _ = fail_with_probability(f64::NAN, 1, return_ok(1), return_err(Error::Timeout));
+}
@@ -1,3 +1,11 @@
+mod arg_actions;
+mod blocking;
+mod call_original;
+mod composite_actions;
+mod error_injection;
+mod fault_injection;
 mod invoke;
 mod return_once;
 mod return_pointee;
+mod save_arg;
+mod set_arg;
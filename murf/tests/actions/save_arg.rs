@@ -0,0 +1,88 @@
+use murf::{
+    action::{save_arg, save_args},
+    expect_call, expect_method_call, mock, mock_fn, LocalContext, Slot,
+};
+
+trait Fuu {
+    fn bar(&self, values: &[u32]);
+    fn baz(&self, x: usize, name: &str);
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn bar(&self, values: &[u32]);
+        fn baz(&self, x: usize, name: &str);
+    }
+}
+
+mock_fn! {
+    fn write_register(addr: u32, value: u32);
+}
+
+mock_fn! {
+    fn reset_register(addr: u32);
+}
+
+#[test]
+fn save_nth_arg() {
+    let x = Slot::new();
+    let name = Slot::new();
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, baz(_, _)).will_once(save_arg::<1, _>(&x));
+    expect_method_call!(handle as Fuu, baz(_, _)).will_once(save_arg::<2, _>(&name));
+
+    assert!(!x.is_set());
+
+    mock.baz(4, "fuu");
+    mock.baz(5, "bar");
+
+    assert_eq!(Some(4), x.get());
+    assert_eq!(Some("bar".to_owned()), name.take());
+    assert!(!name.is_set());
+}
+
+#[test]
+fn save_slice_arg() {
+    let values = Slot::new();
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, bar(_)).will_once(save_arg::<1, _>(&values));
+
+    mock.bar(&[1, 2, 3]);
+
+    assert_eq!(Some(vec![1, 2, 3]), values.get());
+}
+
+#[test]
+fn save_all_args() {
+    let _local_context = LocalContext::new();
+    let args = Slot::new();
+
+    let handle = write_register::handle();
+
+    expect_call!(handle, write_register(_, _)).will_once(save_args(&args));
+
+    write_register(4, 5);
+
+    assert_eq!(Some((4, 5)), args.get());
+}
+
+#[test]
+fn save_single_arg() {
+    let _local_context = LocalContext::new();
+    let addr = Slot::new();
+
+    let handle = reset_register::handle();
+
+    expect_call!(handle, reset_register(_)).will_once(save_arg::<0, _>(&addr));
+
+    reset_register(4);
+
+    assert_eq!(Some(4), addr.get());
+}
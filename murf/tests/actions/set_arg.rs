@@ -0,0 +1,87 @@
+use murf::{
+    action::{do_all, save_arg, set_arg, set_arg_from_slice, Return},
+    expect_method_call, mock, Slot,
+};
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Config {
+    baud_rate: u32,
+}
+
+trait Device {
+    fn read(&mut self, buf: &mut [u8]) -> usize;
+    fn fill(&self, out: &mut Config);
+    fn write(&self, addr: u32, value: &mut u32) -> bool;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyDevice;
+
+    impl Device for MyDevice {
+        fn read(&mut self, buf: &mut [u8]) -> usize;
+        fn fill(&self, out: &mut Config);
+        fn write(&self, addr: u32, value: &mut u32) -> bool;
+    }
+}
+
+#[test]
+fn set_value() {
+    let (handle, mock) = MyDevice::mock_with_handle();
+
+    expect_method_call!(handle as Device, fill(_))
+        .will_once(set_arg::<1, _>(Config { baud_rate: 9600 }));
+
+    let mut config = Config::default();
+    mock.fill(&mut config);
+
+    assert_eq!(9600, config.baud_rate);
+}
+
+#[test]
+fn set_from_slice() {
+    let (handle, mut mock) = MyDevice::mock_with_handle();
+
+    expect_method_call!(handle as Device, read(_))
+        .will_once(do_all((set_arg_from_slice::<1, _>(&[1, 2, 3]), Return(3))));
+
+    let mut buf = [0u8; 5];
+    assert_eq!(3, mock.read(&mut buf));
+    assert_eq!([1, 2, 3, 0, 0], buf);
+}
+
+#[test]
+#[should_panic(expected = "Unable to set argument 1")]
+fn set_from_slice_too_long() {
+    let (handle, mut mock) = MyDevice::mock_with_handle();
+
+    expect_method_call!(handle as Device, read(_))
+        .will_once(do_all((set_arg_from_slice::<1, _>(&[1, 2, 3]), Return(3))));
+
+    let mut buf = [0u8; 2];
+    mock.read(&mut buf);
+}
+
+#[test]
+fn do_all_repeatedly() {
+    let addr = Slot::new();
+
+    let (handle, mock) = MyDevice::mock_with_handle();
+
+    expect_method_call!(handle as Device, write(_, _))
+        .times(2)
+        .will_repeatedly(do_all((
+            save_arg::<1, _>(&addr),
+            set_arg::<2, _>(5),
+            Return(true),
+        )));
+
+    let mut value = 0;
+    assert!(mock.write(1, &mut value));
+    assert_eq!(5, value);
+
+    value = 0;
+    assert!(mock.write(2, &mut value));
+    assert_eq!(5, value);
+    assert_eq!(Some(2), addr.get());
+}
@@ -0,0 +1,83 @@
+use murf::{action::Return, expect_method_call, matcher::eq, mock};
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize;
+    }
+}
+
+#[test]
+fn success() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    let a = expect_method_call!(handle as Fuu, fuu(eq(1))).will_once(Return(1));
+    let b = expect_method_call!(handle as Fuu, fuu(eq(2))).will_once(Return(2));
+    expect_method_call!(handle as Fuu, fuu(eq(3)))
+        .after(&a)
+        .after(&b)
+        .will_once(Return(3));
+
+    assert!(!a.is_satisfied());
+
+    assert_eq!(2, mock.fuu(2));
+    assert_eq!(1, mock.fuu(1));
+    assert_eq!(3, mock.fuu(3));
+
+    assert!(a.is_satisfied());
+}
+
+#[test]
+fn prerequisite_without_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    let a = expect_method_call!(handle as Fuu, fuu(eq(1)))
+        .times(0..)
+        .expectation_ref();
+    expect_method_call!(handle as Fuu, fuu(eq(2)))
+        .after(&a)
+        .will_once(Return(2));
+
+    assert_eq!(2, mock.fuu(2));
+}
+
+#[test]
+fn retired_prerequisite() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    let a = expect_method_call!(handle as Fuu, fuu(eq(1))).will_once(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2)))
+        .after(&a)
+        .will_once(Return(2));
+
+    a.retire();
+
+    assert!(!a.is_satisfied());
+    assert!(a.is_done());
+    assert_eq!(2, mock.fuu(2));
+}
+
+#[test]
+#[should_panic(expected = "No suitable expectation found")]
+fn failure() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    let a = expect_method_call!(handle as Fuu, fuu(eq(1))).will_once(Return(1));
+    let b = expect_method_call!(handle as Fuu, fuu(eq(2))).will_once(Return(2));
+    expect_method_call!(handle as Fuu, fuu(eq(3)))
+        .after(&a)
+        .after(&b)
+        .will_once(Return(3));
+
+    assert_eq!(1, mock.fuu(1));
+
+    handle.release();
+
+    mock.fuu(3);
+}
@@ -0,0 +1,122 @@
+use murf::{
+    action::Return,
+    assert_called,
+    matcher::{eq, gt},
+    mock, on_method_call,
+};
+
+trait Fuu {
+    fn fuu(&self, x: usize, s: String) -> usize;
+    fn bar(&self);
+    fn baz(&self, s: &str);
+    fn qux(&self, data: &[u8], x: &usize);
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize, s: String) -> usize;
+        fn bar(&self);
+        fn baz(&self, s: &str);
+        fn qux(&self, data: &[u8], x: &usize);
+    }
+}
+
+#[test]
+fn success() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_, _)).will_by_default(Return(1));
+    on_method_call!(handle as Fuu, bar()).will_by_default(Return(()));
+
+    mock.fuu(3, "fuu".into());
+    mock.fuu(3, "bar".into());
+    mock.fuu(4, "fuu".into());
+    mock.bar();
+
+    assert_called!(handle as Fuu, fuu(eq(3), _), times = 2);
+    assert_called!(handle as Fuu, fuu(gt(4), eq("fuu".to_string())));
+    assert_called!(handle as Fuu, fuu(_, _), times = 3);
+    assert_called!(handle as Fuu, bar());
+
+    let calls = handle.calls();
+    assert_eq!(
+        Some(&(3, "fuu".to_string())),
+        calls[0].values::<(usize, String)>()
+    );
+    assert_eq!(Some(&()), calls[3].values::<()>());
+}
+
+#[test]
+#[should_panic(expected = "Call assertion for <MyStruct as Fuu>::fuu failed")]
+fn failure() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_, _)).will_by_default(Return(1));
+
+    mock.fuu(2, "fuu".into());
+    mock.fuu(3, "bar".into());
+
+    assert_called!(handle as Fuu, fuu(eq(3), eq("fuu".to_string())));
+}
+
+#[test]
+fn closest_match() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_, _)).will_by_default(Return(1));
+
+    mock.fuu(2, "bar".into());
+    mock.fuu(3, "bar".into());
+
+    let err = handle
+        .as_fuu_assert_fuu()
+        .with(murf::matcher::multi((eq(3), eq("fuu".to_string()))))
+        .try_verify()
+        .unwrap_err();
+
+    let closest = err.lines().find(|l| l.starts_with("- ")).unwrap();
+    assert!(closest.starts_with("- <MyStruct as Fuu>::fuu(3, \"bar\")"));
+}
+
+#[test]
+fn reference_arguments() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, baz(_)).will_by_default(Return(()));
+    on_method_call!(handle as Fuu, qux(_, _)).will_by_default(Return(()));
+
+    mock.baz("fuu");
+    mock.qux(&[1, 2, 3], &4);
+
+    assert_called!(handle as Fuu, baz(eq("fuu")));
+    assert_called!(handle as Fuu, qux(eq(vec![1, 2, 3]), eq(4)));
+
+    let calls = handle.calls();
+    assert_eq!(vec!["\"fuu\"".to_string()], calls[0].args);
+    assert_eq!(Some(&("fuu".to_string(),)), calls[0].values::<(String,)>());
+}
+
+#[test]
+fn disable_recording_of_arguments() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+    handle.set_record_args(false);
+
+    on_method_call!(handle as Fuu, fuu(_, _)).will_by_default(Return(1));
+    on_method_call!(handle as Fuu, bar()).will_by_default(Return(()));
+
+    mock.fuu(3, "fuu".into());
+    mock.bar();
+
+    assert!(handle.calls()[0].values.is_none());
+    assert_called!(handle as Fuu, bar());
+
+    let err = handle
+        .as_fuu_assert_fuu()
+        .with(murf::matcher::multi((eq(3), eq("fuu".to_string()))))
+        .try_verify()
+        .unwrap_err();
+    assert!(err.contains("arguments were not recorded"));
+}
@@ -0,0 +1,83 @@
+use std::future::Future;
+
+use futures::executor::block_on;
+use murf::{action::Return, expect_method_call, matcher::eq, mock};
+
+trait Fuu {
+    async fn fuu(&self, x: usize) -> usize;
+    async fn bar(&self);
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        async fn fuu(&self, _x: usize) -> usize;
+        async fn bar(&self);
+    }
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct LocalStruct;
+
+    impl Fuu for LocalStruct {
+        async fn fuu(&self, _x: usize) -> usize;
+        async fn bar(&self);
+    }
+}
+
+fn assert_send<F: Future + Send>(fut: F) -> F {
+    fut
+}
+
+#[test]
+fn value_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4))).will_once(Return(4));
+    expect_method_call!(handle as Fuu, fuu(eq(5))).will_repeatedly(Return(5));
+
+    assert_eq!(4, block_on(assert_send(mock.fuu(4))));
+    assert_eq!(5, block_on(assert_send(mock.fuu(5))));
+    assert_eq!(5, block_on(assert_send(mock.fuu(5))));
+}
+
+#[test]
+fn async_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4)))
+        .will_once_async(|(_, x): (&_, usize)| async move { x + 1 });
+
+    assert_eq!(5, block_on(assert_send(mock.fuu(4))));
+}
+
+#[test]
+fn default_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, bar());
+
+    block_on(assert_send(mock.bar()));
+}
+
+#[test]
+fn not_send() {
+    let (handle, mock) = LocalStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4))).will_once(Return(4));
+
+    assert_eq!(4, block_on(mock.fuu(4)));
+}
+
+#[test]
+#[should_panic]
+fn failure() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4))).will_once(Return(4));
+
+    block_on(mock.fuu(5));
+}
@@ -0,0 +1,116 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use async_trait::async_trait;
+use futures::executor::block_on;
+use murf::{action::Return, expect_method_call, matcher::eq, mock};
+
+#[async_trait]
+#[allow(clippy::double_must_use)]
+trait Fuu {
+    async fn fuu(&self, x: usize) -> usize;
+    async fn bar(&self);
+    async fn len(&self, s: &str) -> usize;
+}
+
+#[async_trait(?Send)]
+#[allow(clippy::double_must_use)]
+trait LocalFuu {
+    async fn fuu(&self, x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MyStruct;
+
+    #[async_trait]
+    impl Fuu for MyStruct {
+        async fn fuu(&self, _x: usize) -> usize;
+        async fn bar(&self);
+        async fn len(&self, _s: &str) -> usize;
+    }
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct LocalStruct;
+
+    #[async_trait(?Send)]
+    impl LocalFuu for LocalStruct {
+        async fn fuu(&self, _x: usize) -> usize;
+    }
+}
+
+async fn call_dyn(fuu: &dyn Fuu, x: usize) -> usize {
+    fuu.fuu(x).await
+}
+
+#[test]
+fn value_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4))).will_once(Return(4));
+    expect_method_call!(handle as Fuu, fuu(eq(5))).will_repeatedly(Return(5));
+
+    assert_eq!(4, block_on(mock.fuu(4)));
+    assert_eq!(5, block_on(call_dyn(&mock, 5)));
+    assert_eq!(5, block_on(call_dyn(&mock, 5)));
+}
+
+#[test]
+fn async_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4)))
+        .will_once_async(|(_, x): (&_, usize)| async move { x + 1 });
+
+    assert_eq!(5, block_on(mock.fuu(4)));
+}
+
+#[test]
+fn default_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, bar());
+
+    block_on(mock.bar());
+}
+
+#[test]
+fn not_send() {
+    let (handle, mock) = LocalStruct::mock_with_handle();
+
+    expect_method_call!(handle as LocalFuu, fuu(eq(4))).will_once(Return(4));
+
+    assert_eq!(4, block_on(mock.fuu(4)));
+}
+
+#[test]
+#[should_panic]
+fn failure() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4))).will_once(Return(4));
+
+    block_on(mock.fuu(5));
+}
+
+#[test]
+fn reference_argument() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, len(_)).will_once(|(_, s): (&_, &str)| s.len());
+
+    assert_eq!(3, block_on(mock.len("fuu")));
+}
+
+#[test]
+fn failure_without_polling() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4))).will_once(Return(4));
+
+    let result = catch_unwind(AssertUnwindSafe(|| drop(mock.fuu(5))));
+    assert!(result.is_err());
+
+    assert_eq!(4, block_on(mock.fuu(4)));
+}
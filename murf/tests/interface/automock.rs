@@ -0,0 +1,47 @@
+use murf::{action::Return, automock, expect_method_call, matcher::eq};
+
+#[automock(derive(Send, Sync))]
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+
+    fn bar(&self) -> usize {
+        5
+    }
+}
+
+#[automock(MyIterator, type Item = usize)]
+trait MyIter<T> {
+    type Item;
+
+    fn next(&mut self, value: T) -> Option<Self::Item>;
+}
+
+#[test]
+fn success() {
+    let (handle, mock) = MockedFuu::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(4))).will_once(Return(4));
+    expect_method_call!(handle as Fuu, bar());
+
+    assert_eq!(4, mock.fuu(4));
+    assert_eq!(5, mock.bar());
+}
+
+#[test]
+fn generic_with_associated_type() {
+    let (handle, mut mock) = MyIterator::mock_with_handle();
+
+    expect_method_call!(handle as MyIter<u8>, next(eq(1))).will_once(Return(Some(2)));
+
+    assert_eq!(Some(2), mock.next(1u8));
+}
+
+#[test]
+#[should_panic]
+fn failure() {
+    let (handle, mock) = MockedFuu::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_)).will_once(Return(4));
+
+    drop(mock);
+}
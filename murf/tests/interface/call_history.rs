@@ -0,0 +1,145 @@
+use murf::{action::Return, expect_method_call, matcher::eq, mock, on_method_call, CallHistory};
+
+trait Fuu {
+    fn fuu(&self, x: usize, s: &str) -> usize;
+    fn bar(&self, x: NoDebug);
+}
+
+pub struct NoDebug;
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize, s: &str) -> usize;
+        fn bar(&self, x: NoDebug);
+    }
+}
+
+#[test]
+fn calls() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(1), _)).will_once(Return(1));
+    on_method_call!(handle as Fuu, fuu(_, _)).will_by_default(Return(2));
+    expect_method_call!(handle as Fuu, bar(_));
+
+    assert_eq!(1, mock.fuu(1, "one"));
+    assert_eq!(2, mock.fuu(2, "two"));
+    mock.bar(NoDebug);
+
+    let calls = handle.calls();
+    assert_eq!(3, calls.len());
+
+    assert_eq!("<MyStruct as Fuu>::fuu", calls[0].method);
+    assert_eq!(vec!["1".to_string(), "\"one\"".into()], calls[0].args);
+    assert!(calls[0]
+        .expectation
+        .as_ref()
+        .unwrap()
+        .starts_with("<MyStruct as Fuu>::fuu(any, Eq(1), any)"));
+
+    assert_eq!(vec!["2".to_string(), "\"two\"".into()], calls[1].args);
+    assert!(calls[1].expectation.is_some());
+    assert!(calls[0].timestamp <= calls[1].timestamp);
+
+    assert_eq!("<MyStruct as Fuu>::bar", calls[2].method);
+    assert_eq!(vec!["_".to_string()], calls[2].args);
+}
+
+#[test]
+fn calls_of_method() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_, _))
+        .times(2)
+        .will_repeatedly(Return(1));
+    expect_method_call!(handle as Fuu, bar(_));
+
+    mock.fuu(1, "one");
+    mock.bar(NoDebug);
+    mock.fuu(2, "two");
+
+    let calls = handle.as_fuu_calls_fuu();
+    assert_eq!(2, calls.len());
+    assert_eq!(
+        "<MyStruct as Fuu>::fuu(1, \"one\")",
+        calls[0].to_string().split(" handled").next().unwrap()
+    );
+
+    let calls = handle.as_fuu_calls_bar();
+    assert_eq!(1, calls.len());
+
+    handle.clear_calls();
+    assert!(handle.calls().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "No suitable expectation found")]
+fn unhandled_call_is_recorded() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| mock.fuu(1, "one")));
+
+    let calls = handle.calls();
+    assert_eq!(1, calls.len());
+    assert!(calls[0].expectation.is_none());
+
+    std::panic::resume_unwind(result.unwrap_err());
+}
+
+#[test]
+fn disable_recording() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_, _))
+        .times(2)
+        .will_repeatedly(Return(1));
+
+    handle.set_record_calls(false);
+    mock.fuu(1, "one");
+    assert!(handle.calls().is_empty());
+
+    handle.set_record_calls(true);
+    mock.fuu(2, "two");
+    assert_eq!(1, handle.calls().len());
+}
+
+#[test]
+fn limited_capacity() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_, _))
+        .times(3)
+        .will_repeatedly(Return(1));
+
+    handle.set_call_history_capacity(Some(2));
+    mock.fuu(1, "one");
+    mock.fuu(2, "two");
+    mock.fuu(3, "three");
+
+    let calls = handle.calls();
+    assert_eq!(2, calls.len());
+    assert_eq!(vec!["2".to_string(), "\"two\"".into()], calls[0].args);
+    assert_eq!(vec!["3".to_string(), "\"three\"".into()], calls[1].args);
+    assert!(calls[1].expectation.is_some());
+
+    handle.set_call_history_capacity(Some(1));
+    assert_eq!(1, handle.calls().len());
+}
+
+#[test]
+fn default_capacity() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_, _)).will_by_default(Return(1));
+
+    for x in 0..=CallHistory::DEFAULT_CAPACITY {
+        mock.fuu(x, "fuu");
+    }
+
+    let calls = handle.calls();
+    assert_eq!(CallHistory::DEFAULT_CAPACITY, calls.len());
+    assert_eq!(vec!["1".to_string(), "\"fuu\"".into()], calls[0].args);
+}
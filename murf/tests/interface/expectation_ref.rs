@@ -0,0 +1,83 @@
+use std::thread::{sleep, spawn};
+use std::time::{Duration, Instant};
+
+use murf::{action::Return, expect_method_call, matcher::eq, mock};
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize;
+    }
+}
+
+#[test]
+fn inspect() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    let ex = expect_method_call!(handle as Fuu, fuu(eq(1)))
+        .times(1..3)
+        .will_repeatedly(Return(1));
+
+    assert_eq!(0, ex.call_count());
+    assert!(!ex.is_satisfied());
+    assert!(!ex.is_saturated());
+
+    assert_eq!(1, mock.fuu(1));
+
+    assert_eq!(1, ex.call_count());
+    assert!(ex.is_satisfied());
+    assert!(!ex.is_saturated());
+
+    assert_eq!(1, mock.fuu(1));
+
+    assert_eq!(2, ex.call_count());
+    assert!(ex.is_saturated());
+}
+
+#[test]
+fn retire() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(_)).will_once(Return(1));
+    let ex = expect_method_call!(handle as Fuu, fuu(eq(2))).will_once(Return(2));
+
+    ex.retire();
+
+    assert!(ex.is_retired());
+    assert_eq!(1, mock.fuu(2));
+}
+
+#[test]
+fn poll_from_other_thread() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    let ex = expect_method_call!(handle as Fuu, fuu(_))
+        .times(3)
+        .will_repeatedly(Return(1));
+
+    let thread = spawn(move || {
+        for i in 0..3 {
+            mock.fuu(i);
+        }
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !ex.is_satisfied() {
+        assert!(
+            Instant::now() < deadline,
+            "Expectation was not satisfied in time"
+        );
+
+        sleep(Duration::from_millis(1));
+    }
+
+    assert_eq!(3, ex.call_count());
+
+    thread.join().unwrap();
+}
@@ -7,7 +7,7 @@ struct Wrapper<'a, T>(&'a T);
 
 impl<'a, T: Display> Display for Wrapper<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "Wrapper({})", &self.0)
+        write!(f, "Wrapper({})", self.0)
     }
 }
 
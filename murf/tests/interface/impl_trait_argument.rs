@@ -0,0 +1,94 @@
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+
+use murf::{action::invoke, expect_method_call, matcher::any, mock};
+
+trait Fuu {
+    fn register(&self, cb: impl Fn(u8) + Send);
+    fn write(&self, w: &mut dyn Write);
+    fn write_impl(&self, w: &mut impl Write);
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn register(&self, cb: impl Fn(u8) + Send) {
+            cb(1);
+        }
+
+        fn write(&self, _w: &mut dyn Write);
+
+        fn write_impl(&self, w: &mut impl Write) {
+            w.write_str("default").unwrap();
+        }
+    }
+}
+
+#[test]
+fn impl_fn_argument() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, register(any())).will_once(invoke(
+        |(_, cb): (&MyStructMock, &(dyn Fn(u8) + Send))| {
+            cb(5);
+        },
+    ));
+
+    let value = Arc::new(AtomicU8::new(0));
+    let value_cb = value.clone();
+
+    mock.register(move |x| value_cb.store(x, Ordering::Relaxed));
+
+    assert_eq!(5, value.load(Ordering::Relaxed));
+}
+
+#[test]
+fn impl_fn_argument_default_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, register(any()));
+
+    let value = Arc::new(AtomicU8::new(0));
+    let value_cb = value.clone();
+
+    mock.register(move |x| value_cb.store(x, Ordering::Relaxed));
+
+    assert_eq!(1, value.load(Ordering::Relaxed));
+}
+
+#[test]
+fn dyn_argument() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, write(any())).will_once(invoke(
+        |(_, w): (&MyStructMock, &mut dyn Write)| {
+            w.write_str("fuu").unwrap();
+        },
+    ));
+
+    let mut s = String::new();
+    mock.write(&mut s);
+
+    assert_eq!("fuu", s);
+}
+
+#[test]
+fn impl_reference_argument() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, write_impl(any())).will_once(invoke(
+        |(_, w): (&MyStructMock, &mut dyn Write)| {
+            w.write_str("bar").unwrap();
+        },
+    ));
+    expect_method_call!(handle as Fuu, write_impl(any()));
+
+    let mut s = String::new();
+    mock.write_impl(&mut s);
+    mock.write_impl(&mut s);
+
+    assert_eq!("bardefault", s);
+}
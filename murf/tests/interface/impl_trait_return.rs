@@ -0,0 +1,111 @@
+use std::future::{ready, Future};
+
+use futures::{executor::block_on, stream::iter, Stream, StreamExt};
+use murf::{action::Return, expect_method_call, mock};
+
+trait Fuu {
+    fn values(&self) -> impl Iterator<Item = usize>;
+    fn value(&self) -> impl Future<Output = usize> + Send;
+    fn stream(&self) -> impl Stream<Item = usize>;
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn values(&self) -> impl Iterator<Item = usize>;
+        fn value(&self) -> impl Future<Output = usize> + Send;
+        fn stream(&self) -> impl Stream<Item = usize>;
+    }
+}
+
+trait Bar {
+    fn values(&self) -> impl Iterator<Item = usize>;
+}
+
+trait Baz {
+    fn names(&self) -> impl DoubleEndedIterator<Item = &'static str> + 'static;
+    fn adder(&self) -> impl Fn(usize) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyBaz;
+
+    impl Baz for MyBaz {
+        fn names(&self) -> impl DoubleEndedIterator<Item = &'static str> + 'static;
+        fn adder(&self) -> impl Fn(usize) -> usize;
+    }
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyBar;
+
+    impl Bar for MyBar {
+        fn values(&self) -> impl Iterator<Item = usize> {
+            [4, 5].into_iter()
+        }
+    }
+}
+
+#[test]
+fn iterator() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, values()).will_once(Return(vec![1, 2, 3].into_iter()));
+    expect_method_call!(handle as Fuu, values()).will_repeatedly(Return(1..3));
+
+    assert_eq!(vec![1, 2, 3], mock.values().collect::<Vec<_>>());
+    assert_eq!(vec![1, 2], mock.values().collect::<Vec<_>>());
+    assert_eq!(vec![1, 2], mock.values().collect::<Vec<_>>());
+}
+
+#[test]
+fn future() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, value()).will_once(Return(ready(4)));
+
+    assert_eq!(4, block_on(mock.value()));
+}
+
+#[test]
+fn stream() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, stream()).will_once(Return(iter([1, 2])));
+
+    assert_eq!(vec![1, 2], block_on(mock.stream().collect::<Vec<_>>()));
+}
+
+#[test]
+fn default_action() {
+    let (handle, mock) = MyBar::mock_with_handle();
+
+    expect_method_call!(handle as Bar, values());
+
+    assert_eq!(vec![4, 5], mock.values().collect::<Vec<_>>());
+}
+
+#[test]
+fn lifetime_bound() {
+    let (handle, mock) = MyBaz::mock_with_handle();
+
+    expect_method_call!(handle as Baz, names()).will_once(Return(["a", "b"].into_iter()));
+
+    let names = mock.names();
+    drop(mock);
+
+    assert_eq!(vec!["b", "a"], names.rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn closure() {
+    let (handle, mock) = MyBaz::mock_with_handle();
+
+    expect_method_call!(handle as Baz, adder()).will_once(Return(|x: usize| x + 1));
+
+    assert_eq!(5, mock.adder()(4));
+}
@@ -0,0 +1,108 @@
+use murf::{
+    action::Return, expect_call, expect_method_call, matcher::eq, mock, LocalContext, MatchOrder,
+};
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+}
+
+trait Bar {
+    fn bar(x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize;
+    }
+
+    impl Bar for MyStruct {
+        fn bar(_x: usize) -> usize;
+    }
+}
+
+#[test]
+fn oldest_first() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    assert_eq!(MatchOrder::OldestFirst, handle.match_order());
+
+    expect_method_call!(handle as Fuu, fuu(_)).will_once(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2))).will_once(Return(2));
+
+    assert_eq!(1, mock.fuu(2));
+    assert_eq!(2, mock.fuu(2));
+}
+
+#[test]
+fn newest_first() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    handle.set_match_order(MatchOrder::NewestFirst);
+
+    expect_method_call!(handle as Fuu, fuu(_))
+        .times(1..)
+        .will_repeatedly(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2))).will_once(Return(2));
+
+    assert_eq!(2, mock.fuu(2));
+    assert_eq!(1, mock.fuu(2));
+    assert_eq!(1, mock.fuu(3));
+}
+
+#[test]
+#[should_panic(expected = "No suitable expectation found")]
+fn newest_first_failure() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    handle.set_match_order(MatchOrder::NewestFirst);
+
+    expect_method_call!(handle as Fuu, fuu(eq(1))).will_once(Return(1));
+
+    mock.fuu(2);
+}
+
+#[test]
+fn newest_first_local_context() {
+    let local_context = LocalContext::new();
+    local_context.set_match_order(MatchOrder::NewestFirst);
+
+    let (handle, _mock) = MyStruct::mock_with_handle();
+
+    expect_call!(handle as Bar, bar(_)).will_once(Return(1));
+    expect_call!(handle as Bar, bar(eq(2))).will_once(Return(2));
+
+    assert_eq!(2, MyStructMock::bar(2));
+    assert_eq!(1, MyStructMock::bar(2));
+
+    drop(local_context);
+}
+
+#[test]
+fn nested_local_context() {
+    let outer = LocalContext::new();
+    let inner = LocalContext::new();
+
+    /* only changes the order of the outer context */
+    outer.set_match_order(MatchOrder::NewestFirst);
+
+    let (handle, _mock) = MyStruct::mock_with_handle();
+
+    expect_call!(handle as Bar, bar(_)).will_once(Return(1));
+    expect_call!(handle as Bar, bar(eq(2))).will_once(Return(2));
+
+    assert_eq!(1, MyStructMock::bar(2));
+    assert_eq!(2, MyStructMock::bar(2));
+
+    drop(inner);
+
+    expect_call!(handle as Bar, bar(_)).will_once(Return(1));
+    expect_call!(handle as Bar, bar(eq(2))).will_once(Return(2));
+
+    assert_eq!(2, MyStructMock::bar(2));
+    assert_eq!(1, MyStructMock::bar(2));
+
+    drop(outer);
+}
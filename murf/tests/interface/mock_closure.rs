@@ -0,0 +1,92 @@
+use murf::{
+    action::{return_arg, Return},
+    expect_call,
+    matcher::{any, eq, multi, range},
+    mock_closure, InSequence,
+};
+
+fn filter<F: Fn(u32) -> bool>(values: &[u32], f: F) -> Vec<u32> {
+    values.iter().copied().filter(|x| f(*x)).collect()
+}
+
+fn notify(mut f: Box<dyn FnMut(u32, String)>) {
+    f(1, "fuu".into());
+    f(2, "bar".into());
+}
+
+fn once<F: FnOnce() -> usize>(f: F) -> usize {
+    f()
+}
+
+#[test]
+fn fn_closure() {
+    let (handle, closure) = mock_closure::<(u32,), bool>();
+
+    expect_call!(handle, call(range(3..))).will_repeatedly(Return(true));
+    expect_call!(handle, call(_)).will_repeatedly(Return(false));
+
+    assert_eq!(vec![3, 4], filter(&[1, 2, 3, 4], closure));
+}
+
+#[test]
+fn fn_mut_closure() {
+    let _seq = InSequence::default();
+    let (handle, closure) = mock_closure::<(u32, String), ()>();
+
+    expect_call!(handle, call(eq(1), eq("fuu".to_string()))).will_once(Return(()));
+    expect_call!(handle, call(eq(2), eq("bar".to_string()))).will_once(Return(()));
+
+    notify(closure);
+}
+
+#[test]
+fn fn_once_closure() {
+    let (handle, closure) = mock_closure::<(), usize>();
+
+    expect_call!(handle, call()).will_once(Return(4));
+
+    assert_eq!(4, once(closure));
+}
+
+#[test]
+fn builder() {
+    let (handle, closure) = mock_closure::<(u32, u32), u32>();
+
+    handle
+        .expect_call()
+        .with(multi((range(1..4), any())))
+        .times(2)
+        .will_repeatedly(return_arg::<1>());
+
+    assert_eq!(2, closure(1, 2));
+    assert_eq!(5, closure(3, 5));
+}
+
+#[test]
+fn actions_receive_arguments() {
+    let (handle, closure) = mock_closure::<(&str,), usize>();
+
+    expect_call!(handle, call(eq("fuu"))).will_once(Return(4));
+    expect_call!(handle, call(_)).will_repeatedly(|(s,): (&str,)| s.len());
+
+    assert_eq!(4, closure("fuu"));
+    assert_eq!(6, closure("barbaz"));
+}
+
+#[test]
+#[should_panic]
+fn failure() {
+    let (handle, closure) = mock_closure::<(u32,), bool>();
+
+    expect_call!(handle, call(eq(4))).will_once(Return(true));
+
+    closure(5);
+}
+
+#[test]
+#[should_panic]
+fn unfulfilled() {
+    let (handle, _closure) = mock_closure::<(u32,), bool>();
+
+    expect_call!(handle, call(eq(4))).will_once(Return(true));
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use murf::{
+    action::Return, expect_call, matcher::eq, mock_fn, mockable_fn, InSequence, LocalContext,
+};
+
+mock_fn! {
+    pub fn read_register(addr: u32) -> u32;
+
+    fn write_register(addr: u32, value: u32);
+}
+
+#[mockable_fn]
+fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0, |acc, x| acc.wrapping_add(*x))
+}
+
+#[mockable_fn]
+fn parse<T: FromStr + Send + Sync + 'static>(s: &str) -> Option<T> {
+    s.parse().ok()
+}
+
+#[test]
+fn success() {
+    let _local_context = LocalContext::new();
+    let handle = read_register::handle();
+
+    expect_call!(handle, read_register(eq(4))).will_once(Return(1));
+
+    assert_eq!(1, read_register(4));
+}
+
+#[test]
+fn in_sequence() {
+    let _local_context = LocalContext::new();
+    let _seq = InSequence::default();
+    let write = write_register::handle();
+    let read = read_register::handle();
+
+    expect_call!(write, write_register(eq(4), eq(2)));
+    expect_call!(read, read_register(eq(4))).will_once(Return(2));
+
+    write_register(4, 2);
+    assert_eq!(2, read_register(4));
+}
+
+#[test]
+fn default_action() {
+    let _local_context = LocalContext::new();
+    let handle = checksum::handle();
+
+    expect_call!(handle, checksum(_)).times(1);
+    expect_call!(handle, checksum(_)).will_once(Return(5));
+
+    assert_eq!(6, checksum(&[1, 2, 3]));
+    assert_eq!(5, checksum(&[1, 2, 3]));
+}
+
+#[test]
+fn generic() {
+    let _local_context = LocalContext::new();
+    let handle = parse::handle();
+
+    expect_call!(handle, parse::<usize>(_)).times(1);
+
+    assert_eq!(Some(42), parse::<usize>("42"));
+}
+
+#[test]
+fn original_without_expectations() {
+    let _local_context = LocalContext::new();
+
+    assert_eq!(6, checksum(&[1, 2, 3]));
+    assert_eq!(Some(42), parse::<usize>("42"));
+}
+
+#[test]
+fn original_after_expectations_are_dropped() {
+    let _local_context = LocalContext::new();
+
+    {
+        let _local_context = LocalContext::new();
+        let handle = checksum::handle();
+
+        expect_call!(handle, checksum(_)).will_once(Return(5));
+
+        assert_eq!(5, checksum(&[1, 2, 3]));
+    }
+
+    assert_eq!(6, checksum(&[1, 2, 3]));
+}
+
+#[test]
+#[should_panic]
+fn no_body_without_expectations() {
+    let _local_context = LocalContext::new();
+
+    read_register(4);
+}
+
+#[test]
+#[should_panic]
+fn failure() {
+    let _local_context = LocalContext::new();
+    let handle = read_register::handle();
+
+    expect_call!(handle, read_register(eq(4))).will_once(Return(1));
+
+    read_register(5);
+}
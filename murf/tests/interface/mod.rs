@@ -1,24 +1,41 @@
+mod after;
 mod argument_with_default_lifetime;
 mod argument_with_lifetime;
+mod assert_called;
 mod associated_functions;
 mod associated_type_trait;
 mod associated_type_trait_with_lifetime;
+mod async_fn;
+mod async_trait;
+mod automock;
+mod call_history;
 mod clonable_mock;
 mod constructor_with_args;
 mod expect_call;
 mod expect_call_with_const_generics;
 mod expect_call_with_generics;
+mod expectation_ref;
 mod exsiting_type;
 mod generic_associated_type_trait;
 mod generic_trait;
+mod impl_trait_argument;
+mod impl_trait_return;
 mod in_sequence;
 mod local_context;
+mod match_order;
+mod mock_closure;
+mod mock_fn;
 mod mock_lifetime;
 mod no_default;
+mod on_call;
+mod policy;
 mod reference_argument;
+mod retires_on_saturation;
 mod return_self_type;
 mod self_arc;
 mod sequence;
 mod simple_trait;
+mod supertraits;
 mod times;
 mod trait_bound_with_self_type;
+mod try_checkpoint;
@@ -0,0 +1,92 @@
+use murf::{action::Return, expect_method_call, matcher::eq, mock, on_method_call, InSequence};
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+    fn bar(&self) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize {
+            x * 2
+        }
+
+        fn bar(&self) -> usize;
+    }
+}
+
+#[test]
+fn default_behavior() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_)).will_by_default(Return(1));
+    on_method_call!(handle as Fuu, fuu(eq(2))).will_by_default(Return(2));
+
+    assert_eq!(1, mock.fuu(1));
+    assert_eq!(2, mock.fuu(2));
+    assert_eq!(1, mock.fuu(3));
+
+    handle.checkpoint();
+
+    assert_eq!(2, mock.fuu(2));
+}
+
+#[test]
+fn no_default_impl() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    handle.as_fuu_on_call_bar().will_by_default(Return(3));
+
+    assert_eq!(3, mock.bar());
+}
+
+#[test]
+fn expectation_has_priority() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_)).will_by_default(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2))).will_once(Return(5));
+
+    assert_eq!(5, mock.fuu(2));
+    assert_eq!(1, mock.fuu(2));
+}
+
+#[test]
+fn expectation_without_action() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_)).will_by_default(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2)));
+
+    assert_eq!(1, mock.fuu(2));
+}
+
+#[test]
+fn sequence_is_not_affected() {
+    let _seq = InSequence::default();
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_)).will_by_default(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2))).will_once(Return(2));
+    expect_method_call!(handle as Fuu, fuu(eq(3))).will_once(Return(3));
+
+    assert_eq!(2, mock.fuu(2));
+    assert_eq!(1, mock.fuu(4));
+    assert_eq!(3, mock.fuu(3));
+}
+
+#[test]
+#[should_panic(expected = "Mocked object 'MyStruct' has unfulfilled expectations")]
+fn checkpoint_is_not_affected() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(_)).will_by_default(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2))).will_once(Return(2));
+
+    assert_eq!(1, mock.fuu(1));
+
+    handle.checkpoint();
+}
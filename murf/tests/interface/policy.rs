@@ -0,0 +1,82 @@
+use murf::{action::Return, expect_method_call, matcher::eq, mock, Policy};
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+    fn bar(&self) -> usize;
+    fn baz(&self, x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize {
+            x * 2
+        }
+
+        fn bar(&self) -> usize;
+
+        fn baz(&self, x: usize) -> usize {
+            x + 1
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "No suitable expectation found")]
+fn nice() {
+    let mock = MyStructMock::nice();
+
+    expect_method_call!(mock as Fuu, fuu(eq(1))).will_once(Return(5));
+
+    assert_eq!(5, mock.fuu(1));
+    mock.fuu(2);
+}
+
+#[test]
+fn nice_uninteresting_call() {
+    let mock = MyStructMock::nice();
+
+    expect_method_call!(mock as Fuu, fuu(eq(1))).will_once(Return(5));
+
+    assert_eq!(5, mock.fuu(1));
+    assert_eq!(3, mock.baz(2));
+}
+
+#[test]
+#[should_panic(expected = "No suitable expectation found")]
+fn nice_saturated_call() {
+    let mock = MyStructMock::nice();
+
+    expect_method_call!(mock as Fuu, fuu(eq(1))).will_once(Return(5));
+
+    assert_eq!(5, mock.fuu(1));
+    mock.fuu(1);
+}
+
+#[test]
+fn naggy() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    handle.set_policy(Policy::Naggy);
+
+    assert_eq!(Policy::Naggy, handle.policy());
+    assert_eq!(4, mock.fuu(2));
+}
+
+#[test]
+#[should_panic(expected = "No suitable expectation found")]
+fn strict() {
+    let mock = MyStruct::mock();
+
+    mock.fuu(2);
+}
+
+#[test]
+#[should_panic(expected = "No default action specified!")]
+fn nice_without_default_impl() {
+    let mock = MyStructMock::nice();
+
+    mock.bar();
+}
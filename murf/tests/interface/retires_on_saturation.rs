@@ -0,0 +1,64 @@
+use murf::{action::Return, expect_method_call, matcher::eq, mock, MatchOrder};
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize;
+    }
+}
+
+#[test]
+fn newest_first() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    handle.set_match_order(MatchOrder::NewestFirst);
+
+    expect_method_call!(handle as Fuu, fuu(_))
+        .times(1..)
+        .will_repeatedly(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2)))
+        .retires_on_saturation()
+        .will_once(Return(2));
+    expect_method_call!(handle as Fuu, fuu(eq(2)))
+        .retires_on_saturation()
+        .will_once(Return(3));
+
+    assert_eq!(3, mock.fuu(2));
+    assert_eq!(2, mock.fuu(2));
+    assert_eq!(1, mock.fuu(2));
+}
+
+#[test]
+fn oldest_first() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(2)))
+        .retires_on_saturation()
+        .times(2)
+        .will_repeatedly(Return(2));
+    expect_method_call!(handle as Fuu, fuu(_)).will_once(Return(1));
+
+    assert_eq!(2, mock.fuu(2));
+    assert_eq!(2, mock.fuu(2));
+    assert_eq!(1, mock.fuu(2));
+}
+
+#[test]
+#[should_panic(expected = "No suitable expectation found")]
+fn retired_expectation_is_not_used() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(2)))
+        .retires_on_saturation()
+        .will_once(Return(2));
+
+    assert_eq!(2, mock.fuu(2));
+
+    mock.fuu(2);
+}
@@ -7,6 +7,7 @@ mock! {
     #[derive(Default, Clone, Send, Sync)]
     pub struct Wakeable;
 
+    #[allow(clippy::manual_noop_waker)]
     impl Wake for Wakeable {
         fn wake(self: Arc<Self>);
         fn wake_by_ref(self: &Arc<Self>);
@@ -0,0 +1,82 @@
+use murf::{action::Return, expect_method_call, matcher::eq, mock};
+
+trait Sensor {
+    fn read(&self) -> u32;
+}
+
+trait Named {
+    fn name(&self) -> String {
+        "device".into()
+    }
+}
+
+trait Device: Sensor + Named + Send {
+    fn scale(&self, factor: u32) -> u32;
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MyDevice;
+
+    #[murf(supertraits(Sensor { fn read(&self) -> u32; }, Named, Send))]
+    impl Device for MyDevice {
+        fn scale(&self, factor: u32) -> u32;
+    }
+}
+
+mock! {
+    #[derive(Default, Send, Sync)]
+    pub struct MySeparateDevice;
+
+    impl Sensor for MySeparateDevice {
+        fn read(&self) -> u32;
+    }
+
+    impl Named for MySeparateDevice { }
+
+    impl Device for MySeparateDevice {
+        fn scale(&self, factor: u32) -> u32;
+    }
+}
+
+fn measure<D: Device>(device: &D) -> u32 {
+    device.scale(device.read())
+}
+
+#[test]
+fn success() {
+    let (handle, mock) = MyDevice::mock_with_handle();
+
+    expect_method_call!(handle as Sensor, read()).will_once(Return(4));
+    expect_method_call!(handle as Device, scale(eq(4))).will_once(Return(8));
+
+    assert_eq!(8, measure(&mock));
+}
+
+#[test]
+fn separate_impl_blocks() {
+    let (handle, mock) = MySeparateDevice::mock_with_handle();
+
+    expect_method_call!(handle as Sensor, read()).will_once(Return(4));
+    expect_method_call!(handle as Device, scale(eq(4))).will_once(Return(8));
+
+    assert_eq!(8, measure(&mock));
+    assert_eq!("device", mock.name());
+}
+
+#[test]
+fn default_method() {
+    let mock = MyDevice::mock();
+
+    assert_eq!("device", mock.name());
+}
+
+#[test]
+#[should_panic]
+fn failure() {
+    let (handle, mock) = MyDevice::mock_with_handle();
+
+    expect_method_call!(handle as Device, scale(eq(4))).will_once(Return(8));
+
+    measure(&mock);
+}
@@ -0,0 +1,93 @@
+use murf::{
+    action::Return, expect_call, expect_method_call, matcher::eq, mock, mock_closure, Sequence,
+};
+
+trait Fuu {
+    fn fuu(&self, x: usize) -> usize;
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize) -> usize;
+    }
+}
+
+#[test]
+fn success() {
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(1))).will_once(Return(1));
+
+    assert_eq!(1, mock.fuu(1));
+    assert!(handle.try_checkpoint().is_ok());
+}
+
+#[test]
+fn failure() {
+    let seq = Sequence::default();
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(eq(1)))
+        .in_sequence(&seq)
+        .times(2..)
+        .will_repeatedly(Return(1));
+    expect_method_call!(handle as Fuu, fuu(eq(2)))
+        .in_sequence(&seq)
+        .will_once(Return(2));
+
+    assert_eq!(1, mock.fuu(1));
+
+    let report = handle.try_checkpoint().unwrap_err();
+
+    assert_eq!("Mocked object 'MyStruct'", report.object);
+    assert_eq!(2, report.unfulfilled.len());
+
+    let ex = &report.unfulfilled[0];
+    assert!(ex
+        .description
+        .starts_with("<MyStruct as Fuu>::fuu(any, Eq(1))"));
+    assert_eq!("2..", ex.times.to_string());
+    assert_eq!(1, ex.call_count);
+    assert!(ex.sequences[0].is_active);
+
+    let ex = &report.unfulfilled[1];
+    assert_eq!("1", ex.times.to_string());
+    assert_eq!(0, ex.call_count);
+    assert!(!ex.sequences[0].is_active);
+
+    assert!(report
+        .to_string()
+        .starts_with("Mocked object 'MyStruct' has unfulfilled expectations"));
+
+    /* expectations are removed by the checkpoint */
+    assert!(handle.try_checkpoint().is_ok());
+}
+
+#[test]
+fn locked() {
+    let (handle, _mock) = MyStruct::mock_with_handle();
+
+    let guard = handle.shared.lock();
+    let report = handle.try_checkpoint().unwrap_err();
+    drop(guard);
+
+    assert!(report.locked);
+    assert!(report.unfulfilled.is_empty());
+    assert!(report.title().contains("Unable to lock handle"));
+    assert!(handle.try_checkpoint().is_ok());
+}
+
+#[test]
+fn closure() {
+    let (handle, _closure) = mock_closure::<(usize,), usize>();
+
+    expect_call!(handle, call(eq(1))).will_once(Return(1));
+
+    let report = handle.try_checkpoint().unwrap_err();
+
+    assert_eq!("Mocked closure", report.object);
+    assert_eq!(1, report.unfulfilled.len());
+}
@@ -0,0 +1,89 @@
+use murf::{
+    action::Return,
+    expect_method_call,
+    matcher::{any, capture, eq, range},
+    mock, on_method_call, Slot,
+};
+
+trait Fuu {
+    fn fuu(&self, x: usize, name: &str) -> usize;
+    fn baz(&self, x: usize, name: &str);
+}
+
+mock! {
+    #[derive(Default)]
+    pub struct MyStruct;
+
+    impl Fuu for MyStruct {
+        fn fuu(&self, x: usize, name: &str) -> usize;
+        fn baz(&self, x: usize, name: &str);
+    }
+}
+
+#[test]
+fn capture_matcher() {
+    let x = Slot::new();
+    let name = Slot::new();
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(
+        handle as Fuu,
+        fuu(capture(&x, range(4..)), capture(&name, eq("fuu")))
+    )
+    .will_once(Return(1));
+    expect_method_call!(handle as Fuu, baz(capture(&x, any()), _)).times(2);
+
+    assert_eq!(1, mock.fuu(4, "fuu"));
+    assert_eq!(Some(4), x.get());
+    assert_eq!(Some("fuu".to_owned()), name.get());
+
+    mock.baz(7, "bar");
+    mock.baz(8, "baz");
+
+    assert_eq!(Some(8), x.get());
+}
+
+#[test]
+fn capture_only_on_match() {
+    let x = Slot::new();
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(capture(&x, range(4..)), _)).will_once(Return(1));
+    expect_method_call!(handle as Fuu, fuu(_, _)).will_once(Return(2));
+
+    assert_eq!(2, mock.fuu(2, "fuu"));
+    assert!(!x.is_set());
+    assert_eq!(1, mock.fuu(5, "fuu"));
+    assert_eq!(Some(5), x.get());
+}
+
+#[test]
+fn capture_only_if_handled() {
+    let x = Slot::new();
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    expect_method_call!(handle as Fuu, fuu(capture(&x, range(4..)), _)).will_once(Return(1));
+    expect_method_call!(handle as Fuu, fuu(_, _)).will_once(Return(2));
+
+    assert_eq!(1, mock.fuu(5, "fuu"));
+    assert_eq!(2, mock.fuu(9, "fuu"));
+    assert_eq!(Some(5), x.get());
+}
+
+#[test]
+fn capture_by_default_behavior() {
+    let x = Slot::new();
+
+    let (handle, mock) = MyStruct::mock_with_handle();
+
+    on_method_call!(handle as Fuu, fuu(capture(&x, any()), _)).will_by_default(Return(3));
+    expect_method_call!(handle as Fuu, fuu(eq(1), _)).will_once(Return(1));
+
+    assert_eq!(1, mock.fuu(1, "fuu"));
+    assert!(!x.is_set());
+    assert_eq!(3, mock.fuu(2, "fuu"));
+    assert_eq!(Some(2), x.get());
+}
@@ -1,3 +1,4 @@
+mod capture;
 mod deref;
 mod multi_args;
 mod range;